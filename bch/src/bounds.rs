@@ -0,0 +1,108 @@
+//! Bounds on the number of codewords of a code of length `n` and minimum distance `d`
+//! over an alphabet of size `q`.
+
+use crate::CodingError;
+
+/// Number of words within Hamming distance `radius` of a fixed word, Σ C(n, i) (q - 1)^i.
+pub fn hamming_ball_volume(q: u128, n: u32, radius: u32) -> Result<u128, CodingError> {
+    check(q, n, 1)?;
+    let mut volume = 0u128;
+    let mut term = 1u128;
+    for i in 0..=radius.min(n) {
+        if i > 0 {
+            // C(n, i) (q - 1)^i from C(n, i - 1) (q - 1)^(i - 1); the division is exact
+            term = (q - 1)
+                .checked_mul((n - i + 1) as u128)
+                .and_then(|factor| term.checked_mul(factor))
+                .ok_or(TOO_LARGE)?
+                / i as u128;
+        }
+        volume = volume.checked_add(term).ok_or(TOO_LARGE)?;
+    }
+    Ok(volume)
+}
+
+/// Singleton bound: at most q^(n - d + 1) codewords, i.e. `k <= n - d + 1`.
+pub fn singleton(q: u128, n: u32, d: u32) -> Result<u128, CodingError> {
+    check(q, n, d)?;
+    q.checked_pow(n - d + 1).ok_or(TOO_LARGE)
+}
+
+/// Hamming (sphere-packing) bound: the balls of radius ⌊(d - 1) / 2⌋ around the codewords
+/// are disjoint, so there are at most q^n / V(n, ⌊(d - 1) / 2⌋) codewords.
+pub fn hamming_sphere_packing(q: u128, n: u32, d: u32) -> Result<u128, CodingError> {
+    check(q, n, d)?;
+    Ok(q.checked_pow(n).ok_or(TOO_LARGE)? / hamming_ball_volume(q, n, (d - 1) / 2)?)
+}
+
+/// Gilbert–Varshamov bound: a code with at least ⌈q^n / V(n, d - 1)⌉ codewords always exists.
+pub fn gilbert_varshamov(q: u128, n: u32, d: u32) -> Result<u128, CodingError> {
+    check(q, n, d)?;
+    Ok(q.checked_pow(n)
+        .ok_or(TOO_LARGE)?
+        .div_ceil(hamming_ball_volume(q, n, d - 1)?))
+}
+
+const TOO_LARGE: CodingError = CodingError::InvalidParameters("Bound does not fit into u128");
+
+fn check(q: u128, n: u32, d: u32) -> Result<(), CodingError> {
+    if q < 2 {
+        return Err(CodingError::InvalidParameters(
+            "Alphabet should have at least 2 symbols",
+        ));
+    }
+    if d == 0 || d > n {
+        return Err(CodingError::InvalidParameters(
+            "Distance should be between 1 and the code length",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BCH;
+
+    #[test]
+    fn test_perfect_codes() {
+        // Hamming [7, 4, 3] and the binary and ternary Golay codes meet the Hamming bound
+        assert_eq!(hamming_sphere_packing(2, 7, 3), Ok(16));
+        assert_eq!(hamming_ball_volume(2, 23, 3), Ok(2048));
+        assert_eq!(hamming_sphere_packing(2, 23, 7), Ok(1 << 12));
+        assert_eq!(hamming_ball_volume(3, 11, 2), Ok(243));
+        assert_eq!(hamming_sphere_packing(3, 11, 5), Ok(729));
+    }
+
+    #[test]
+    fn test_small_parameters() {
+        assert_eq!(singleton(2, 7, 3), Ok(32));
+        assert_eq!(gilbert_varshamov(2, 7, 3), Ok(5));
+        assert_eq!(singleton(2, 5, 5), Ok(2));
+        assert_eq!(hamming_sphere_packing(2, 5, 5), Ok(2));
+        assert_eq!(gilbert_varshamov(2, 5, 1), Ok(32));
+        // Reed–Solomon codes over GF(16) are MDS
+        assert_eq!(singleton(16, 15, 7), Ok(16u128.pow(9)));
+        assert_eq!(hamming_ball_volume(2, 3, 10), Ok(8));
+    }
+
+    #[test]
+    fn test_bch_between_bounds() {
+        let bch = BCH::<4>::from_distance(7).unwrap();
+        let (n, d) = (bch.code_length() as u32, bch.distance() as u32);
+        let size = 1u128 << bch.message_length();
+        assert!(size <= hamming_sphere_packing(2, n, d).unwrap());
+        assert!(size <= singleton(2, n, d).unwrap());
+        assert!(gilbert_varshamov(2, n, d).unwrap() <= size);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(singleton(1, 7, 3).is_err());
+        assert!(singleton(2, 7, 0).is_err());
+        assert!(gilbert_varshamov(2, 7, 8).is_err());
+        assert!(hamming_sphere_packing(2, 200, 3).is_err());
+        assert!(singleton(2, 128, 1).is_err());
+        assert_eq!(singleton(2, 128, 2), Ok(1 << 127));
+    }
+}
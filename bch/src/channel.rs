@@ -0,0 +1,294 @@
+#[cfg(feature = "rand")]
+use crate::CodingError;
+#[cfg(feature = "rand")]
+use alloc::vec::Vec;
+use galois::PolyGF2;
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+// Number of positions a `PolyGF2` can hold
+const WORD_BITS: usize = u32::BITS as usize;
+
+/// Flips the bits of `codeword` at the given positions. Listing a position twice flips it back.
+///
+/// # Panics
+///
+/// Panics if a position does not fit into a `PolyGF2`, i.e. is 32 or more.
+pub fn inject_errors(codeword: PolyGF2, positions: &[usize]) -> PolyGF2 {
+    if positions.iter().any(|&i| i >= WORD_BITS) {
+        panic!("Error positions should be below 32");
+    }
+    let error = positions.iter().fold(0, |acc, i| acc ^ 1 << i);
+    PolyGF2::new(codeword.poly ^ error)
+}
+
+/// Flips exactly `count` distinct bits of `codeword`, chosen uniformly among the lowest
+/// `code_length`.
+///
+/// # Panics
+///
+/// Panics if `count` exceeds `code_length`, or if `code_length` exceeds the 32 bits of a
+/// `PolyGF2`.
+#[cfg(feature = "rand")]
+pub fn inject_random_errors<R: Rng + ?Sized>(
+    codeword: PolyGF2,
+    count: usize,
+    code_length: usize,
+    rng: &mut R,
+) -> PolyGF2 {
+    check_code_length(code_length);
+    if count > code_length {
+        panic!("Cannot inject more errors than the code length");
+    }
+    // Partial Fisher-Yates shuffle, the first `count` entries end up a uniform sample
+    let mut positions: Vec<usize> = (0..code_length).collect();
+    for i in 0..count {
+        let j = rng.gen_range(i..code_length);
+        positions.swap(i, j);
+    }
+    inject_errors(codeword, &positions[..count])
+}
+
+/// Binary symmetric channel: every transmitted bit is flipped independently with probability `p`.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinarySymmetricChannel {
+    p: f64,
+}
+
+#[cfg(feature = "rand")]
+impl BinarySymmetricChannel {
+    pub fn new(p: f64) -> Result<Self, CodingError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(CodingError::InvalidParameters(
+                "Crossover probability should be between 0 and 1",
+            ));
+        }
+        Ok(BinarySymmetricChannel { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Sends the lowest `code_length` bits of `codeword` through the channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code_length` exceeds the 32 bits of a `PolyGF2`.
+    pub fn transmit<R: Rng + ?Sized>(
+        &self,
+        codeword: PolyGF2,
+        code_length: usize,
+        rng: &mut R,
+    ) -> PolyGF2 {
+        check_code_length(code_length);
+        let error = (0..code_length)
+            .filter(|_| rng.gen_bool(self.p))
+            .fold(0, |acc, i| acc | 1 << i);
+        PolyGF2::new(codeword.poly ^ error)
+    }
+}
+
+/// Binary erasure channel: every transmitted bit is lost independently with probability `p`,
+/// and the receiver knows which ones were lost.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinaryErasureChannel {
+    p: f64,
+}
+
+#[cfg(feature = "rand")]
+impl BinaryErasureChannel {
+    pub fn new(p: f64) -> Result<Self, CodingError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(CodingError::InvalidParameters(
+                "Erasure probability should be between 0 and 1",
+            ));
+        }
+        Ok(BinaryErasureChannel { p })
+    }
+
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Sends the lowest `code_length` bits of `codeword` through the channel. Returns the
+    /// received word, with erased bits set to 0, and the erased positions in increasing order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code_length` exceeds the 32 bits of a `PolyGF2`.
+    pub fn transmit<R: Rng + ?Sized>(
+        &self,
+        codeword: PolyGF2,
+        code_length: usize,
+        rng: &mut R,
+    ) -> (PolyGF2, Vec<usize>) {
+        check_code_length(code_length);
+        let erasures: Vec<usize> = (0..code_length).filter(|_| rng.gen_bool(self.p)).collect();
+        let mask = erasures.iter().fold(0, |acc, i| acc | 1 << i);
+        (PolyGF2::new(codeword.poly & !mask), erasures)
+    }
+}
+
+#[cfg(feature = "rand")]
+fn check_code_length(code_length: usize) {
+    if code_length > WORD_BITS {
+        panic!("Code length should be at most 32");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "rand")]
+    use rand::rngs::SmallRng;
+    #[cfg(feature = "rand")]
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_inject_errors() {
+        let codeword = PolyGF2::new(0b101100111000101);
+        assert_eq!(
+            inject_errors(codeword, &[5, 13]),
+            PolyGF2::new(codeword.poly ^ 0b10000000100000)
+        );
+        assert_eq!(inject_errors(codeword, &[]), codeword);
+        assert_eq!(inject_errors(codeword, &[7, 7]), codeword);
+        assert_eq!(inject_errors(PolyGF2::new(0), &[31]), PolyGF2::new(1 << 31));
+    }
+
+    #[test]
+    #[should_panic(expected = "Error positions should be below 32")]
+    fn test_inject_errors_out_of_range() {
+        inject_errors(PolyGF2::new(0), &[3, 40]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic(expected = "Code length should be at most 32")]
+    fn test_inject_random_errors_too_long() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        inject_random_errors(PolyGF2::new(0), 1, 33, &mut rng);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_inject_random_errors() {
+        let mut rng = SmallRng::seed_from_u64(4);
+        let codeword = PolyGF2::new(0b101100111000101);
+        let mut hits = [0; 15];
+        for _ in 0..1000 {
+            let received = inject_random_errors(codeword, 3, 15, &mut rng);
+            let error = received.poly ^ codeword.poly;
+            assert_eq!(error.count_ones(), 3);
+            assert_eq!(error >> 15, 0);
+            for (i, hit) in hits.iter_mut().enumerate() {
+                *hit += error >> i & 1;
+            }
+        }
+        // 200 hits expected per position
+        assert!(hits.iter().all(|hit| (140..260).contains(hit)), "{hits:?}");
+        let all = inject_random_errors(codeword, 15, 15, &mut rng);
+        assert_eq!(all, PolyGF2::new(codeword.poly ^ 0x7fff));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_bsc_extremes() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let codeword = PolyGF2::new(0b101100111000101);
+
+        let noiseless = BinarySymmetricChannel::new(0.0).unwrap();
+        assert_eq!(noiseless.transmit(codeword, 15, &mut rng), codeword);
+
+        let inverting = BinarySymmetricChannel::new(1.0).unwrap();
+        assert_eq!(
+            inverting.transmit(codeword, 15, &mut rng),
+            PolyGF2::new(codeword.poly ^ 0x7fff)
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic(expected = "Code length should be at most 32")]
+    fn test_bsc_too_long() {
+        let mut rng = SmallRng::seed_from_u64(6);
+        let channel = BinarySymmetricChannel::new(0.5).unwrap();
+        channel.transmit(PolyGF2::new(0), 40, &mut rng);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_bsc_error_rate() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let channel = BinarySymmetricChannel::new(0.1).unwrap();
+        let flipped: u32 = (0..1000)
+            .map(|_| {
+                channel
+                    .transmit(PolyGF2::new(0), 31, &mut rng)
+                    .poly
+                    .count_ones()
+            })
+            .sum();
+        // 31000 bits, about 3100 flips with a standard deviation of about 53
+        assert!((2800..3400).contains(&flipped), "{flipped}");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_bec() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let codeword = PolyGF2::new(0b111011011101111);
+
+        let lossless = BinaryErasureChannel::new(0.0).unwrap();
+        assert_eq!(
+            lossless.transmit(codeword, 15, &mut rng),
+            (codeword, vec![])
+        );
+        let lossy = BinaryErasureChannel::new(1.0).unwrap();
+        let (received, erasures) = lossy.transmit(codeword, 15, &mut rng);
+        assert_eq!(received, PolyGF2::new(0));
+        assert_eq!(erasures, (0..15).collect::<Vec<_>>());
+
+        let channel = BinaryErasureChannel::new(0.2).unwrap();
+        let mut counts = [0; 16];
+        for _ in 0..2000 {
+            let (received, erasures) = channel.transmit(codeword, 15, &mut rng);
+            assert!(erasures.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(erasures.iter().all(|&i| i < 15));
+            for i in 0..15 {
+                let bit = received.poly >> i & 1;
+                if !erasures.contains(&i) {
+                    assert_eq!(bit, codeword.poly >> i & 1);
+                } else {
+                    assert_eq!(bit, 0);
+                }
+            }
+            counts[erasures.len()] += 1;
+        }
+        // Binomial(15, 0.2): mean 3, with 2 to 4 erasures about 60% of the time
+        let total: usize = counts.iter().enumerate().map(|(k, c)| k * c).sum();
+        assert!((5400..6600).contains(&total), "{total}");
+        assert!(counts[2..=4].iter().sum::<usize>() > 1000, "{counts:?}");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic(expected = "Code length should be at most 32")]
+    fn test_bec_too_long() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let channel = BinaryErasureChannel::new(0.5).unwrap();
+        channel.transmit(PolyGF2::new(0), 33, &mut rng);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_invalid_probability() {
+        assert!(BinarySymmetricChannel::new(-0.1).is_err());
+        assert!(BinarySymmetricChannel::new(1.5).is_err());
+        assert!(BinarySymmetricChannel::new(f64::NAN).is_err());
+        assert!(BinaryErasureChannel::new(1.01).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::CodingError;
+use galois::PolyGF2;
+
+/// Cyclic redundancy check with a given generator polynomial, processing bytes most
+/// significant bit first.
+///
+/// The generator includes its leading term, e.g. `0x11021` for CRC-16-CCITT.
+/// Widths up to 24 bits are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc {
+    generator_poly: PolyGF2,
+    init: u32,
+    xor_out: u32,
+}
+
+impl Crc {
+    pub fn new(generator_poly: PolyGF2) -> Result<Self, CodingError> {
+        if generator_poly.poly < 2 || generator_poly.degree() > 24 {
+            return Err(CodingError::InvalidParameters(
+                "CRC width should be between 1 and 24 bits",
+            ));
+        }
+        Ok(Crc {
+            generator_poly,
+            init: 0,
+            xor_out: 0,
+        })
+    }
+
+    /// Register contents before the first byte is processed.
+    pub fn with_init(self, init: u32) -> Self {
+        Crc {
+            init: init & self.mask(),
+            ..self
+        }
+    }
+
+    /// Value XORed into the register after the last byte.
+    pub fn with_xor_out(self, xor_out: u32) -> Self {
+        Crc {
+            xor_out: xor_out & self.mask(),
+            ..self
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.generator_poly.degree()
+    }
+
+    pub fn checksum(&self, data: &[u8]) -> u32 {
+        // Every byte shifts the register by x^8 and enters at x^width, i.e. the register is
+        // the remainder of the message so far times x^width
+        let width = self.width();
+        let register = data.iter().fold(PolyGF2::new(self.init), |register, byte| {
+            let shifted = register * PolyGF2::new(1 << 8) + PolyGF2::new((*byte as u32) << width);
+            shifted.divmod(self.generator_poly).1
+        });
+        register.poly ^ self.xor_out
+    }
+
+    fn mask(&self) -> u32 {
+        (1 << self.width()) - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn test_crc16_ccitt() {
+        let xmodem = Crc::new(PolyGF2::new(0x11021)).unwrap();
+        assert_eq!(xmodem.width(), 16);
+        assert_eq!(xmodem.checksum(CHECK), 0x31c3);
+
+        let ccitt_false = xmodem.with_init(0xffff);
+        assert_eq!(ccitt_false.checksum(CHECK), 0x29b1);
+
+        let genibus = ccitt_false.with_xor_out(0xffff);
+        assert_eq!(genibus.checksum(CHECK), 0xd64e);
+    }
+
+    #[test]
+    fn test_crc8() {
+        let crc8 = Crc::new(PolyGF2::new(0x107)).unwrap();
+        assert_eq!(crc8.checksum(CHECK), 0xf4);
+        assert_eq!(crc8.checksum(&[]), 0);
+    }
+
+    #[test]
+    fn test_appended_checksum_leaves_no_remainder() {
+        let crc = Crc::new(PolyGF2::new(0x11021)).unwrap();
+        let checksum = crc.checksum(CHECK);
+        let mut framed = CHECK.to_vec();
+        framed.extend_from_slice(&(checksum as u16).to_be_bytes());
+        assert_eq!(crc.checksum(&framed), 0);
+
+        framed[3] ^= 0b100;
+        assert_ne!(crc.checksum(&framed), 0);
+    }
+
+    #[test]
+    fn test_invalid_width() {
+        assert!(Crc::new(PolyGF2::new(1)).is_err());
+        assert!(Crc::new(PolyGF2::new(1 << 25 | 1)).is_err());
+    }
+}
@@ -0,0 +1,365 @@
+use crate::{CodingError, DecodeResult, Decoder, Encoder};
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use core::cell::OnceCell;
+use galois::PolyGF2;
+use num_traits::Zero;
+#[cfg(feature = "std")]
+use std::sync::OnceLock as OnceCell;
+
+/// A binary cyclic `(n, k)` code given by its generator polynomial `g(x)`, which must divide
+/// `x^n - 1`. BCH codes are the special case where `g` is built from consecutive roots.
+///
+/// Decoding looks the syndrome `r(x) mod g(x)` up in a table of coset leaders, covering every
+/// error pattern up to the largest weight whose patterns all have distinct syndromes. The
+/// table is built on first use, so encoding-only codes never pay for it.
+#[derive(Debug, Clone)]
+pub struct CyclicCode {
+    generator_poly: PolyGF2,
+    code_length: usize,
+    message_length: usize,
+    // The correctable weight t and the leaders of weight <= t, indexed by syndrome
+    syndrome_table: OnceCell<(usize, BTreeMap<u32, u32>)>,
+    distance: OnceCell<usize>,
+}
+
+impl CyclicCode {
+    pub fn new(generator_poly: PolyGF2, code_length: usize) -> Result<Self, CodingError> {
+        if code_length == 0 || code_length > 31 {
+            return Err(CodingError::InvalidParameters(
+                "Code length should be between 1 and 31",
+            ));
+        }
+        if generator_poly.is_zero() || generator_poly.degree() >= code_length {
+            return Err(CodingError::InvalidParameters(
+                "Generator degree should be below the code length",
+            ));
+        }
+        if !(PolyGF2::new(1 << code_length | 1) % generator_poly).is_zero() {
+            return Err(CodingError::InvalidParameters(
+                "Generator polynomial should divide x^n - 1",
+            ));
+        }
+
+        Ok(CyclicCode {
+            generator_poly,
+            code_length,
+            message_length: code_length - generator_poly.degree(),
+            syndrome_table: OnceCell::new(),
+            distance: OnceCell::new(),
+        })
+    }
+
+    pub fn code_length(&self) -> usize {
+        self.code_length
+    }
+
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    pub fn generator_poly(&self) -> PolyGF2 {
+        self.generator_poly
+    }
+
+    /// Number of errors the syndrome decoder is guaranteed to correct.
+    pub fn max_errors(&self) -> usize {
+        self.table().0
+    }
+
+    /// True minimum distance, i.e. the smallest weight of a nonzero codeword. Found without
+    /// the syndrome table, by trying error patterns in order of weight or by encoding every
+    /// message, whichever takes fewer steps, and cached afterwards.
+    pub fn minimum_distance(&self) -> usize {
+        *self.distance.get_or_init(|| {
+            let n = self.code_length;
+            let mut patterns = 0u64;
+            let mut patterns_of_weight = 1u64;
+            for weight in 1..=n {
+                patterns_of_weight = patterns_of_weight * (n - weight + 1) as u64 / weight as u64;
+                patterns += patterns_of_weight;
+                if patterns >> self.message_length != 0 {
+                    break;
+                }
+                let mut pattern = (1u32 << weight) - 1;
+                while pattern >> n == 0 {
+                    if self.syndrome(PolyGF2::new(pattern)).is_zero() {
+                        return weight;
+                    }
+                    pattern = next_combination(pattern);
+                }
+            }
+            (1..1u32 << self.message_length)
+                .map(|message| self.encode(PolyGF2::new(message)).unwrap().weight() as usize)
+                .min()
+                .unwrap()
+        })
+    }
+
+    /// Systematic encoding: the message occupies the top `k` positions.
+    pub fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        if message.poly >> self.message_length != 0 {
+            return Err(CodingError::MessageTooLong);
+        }
+        let shifted = message * PolyGF2::new(1 << self.generator_poly.degree());
+        Ok(shifted + shifted % self.generator_poly)
+    }
+
+    /// Syndrome decoding by table lookup; returns the message together with the
+    /// corrected positions.
+    pub fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length != 0 {
+            return Err(CodingError::WrongLength);
+        }
+        let syndrome = self.syndrome(received);
+        let error = self
+            .table()
+            .1
+            .get(&syndrome.poly)
+            .ok_or(CodingError::Uncorrectable)?;
+        let error_positions = (0..self.code_length)
+            .filter(|i| error >> i & 1 == 1)
+            .collect();
+        Ok(DecodeResult::new(
+            PolyGF2::new((received.poly ^ error) >> self.generator_poly.degree()),
+            error_positions,
+            0,
+        ))
+    }
+
+    /// Meggitt decoder: rotates the received word through all `n` cyclic shifts while a
+    /// syndrome register follows along, and flips the highest bit whenever the register
+    /// holds the syndrome of a correctable pattern with an error there.
+    ///
+    /// Corrects the same error patterns as `decode`. In hardware the register is an LFSR with
+    /// feedback `g(x)`, and only syndromes of patterns with a top error need recognizing.
+    pub fn decode_meggitt(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length != 0 {
+            return Err(CodingError::WrongLength);
+        }
+        let n = self.code_length;
+        let top = 1u32 << (n - 1);
+        let top_syndrome = self.syndrome(PolyGF2::new(top));
+        let x = PolyGF2::new(0b10);
+
+        let mut word = received.poly;
+        let mut syndrome = self.syndrome(received);
+        for _ in 0..n {
+            let has_top_error = self
+                .table()
+                .1
+                .get(&syndrome.poly)
+                .is_some_and(|error| error & top != 0);
+            if has_top_error {
+                word ^= top;
+                syndrome += top_syndrome;
+            }
+            // Cyclic shift r(x) -> x r(x) mod (x^n - 1), one LFSR step for the register
+            word = (word << 1 | word >> (n - 1)) & ((top << 1) - 1);
+            syndrome = (syndrome * x) % self.generator_poly;
+        }
+        if !syndrome.is_zero() {
+            return Err(CodingError::Uncorrectable);
+        }
+        // After n shifts the word is back in place, so the flipped bits are the errors
+        let error_positions = (0..n)
+            .filter(|i| (word ^ received.poly) >> i & 1 == 1)
+            .collect();
+        Ok(DecodeResult::new(
+            PolyGF2::new(word >> self.generator_poly.degree()),
+            error_positions,
+            0,
+        ))
+    }
+
+    fn table(&self) -> &(usize, BTreeMap<u32, u32>) {
+        self.syndrome_table
+            .get_or_init(|| syndrome_table(self.generator_poly, self.code_length))
+    }
+
+    /// Remainder of the received word modulo the generator; zero exactly for codewords.
+    pub fn syndrome(&self, received: PolyGF2) -> PolyGF2 {
+        received % self.generator_poly
+    }
+}
+
+impl Encoder for CyclicCode {
+    type Message = PolyGF2;
+    type Codeword = PolyGF2;
+
+    fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        CyclicCode::encode(self, message)
+    }
+
+    fn parameters(&self) -> (usize, usize, usize) {
+        (
+            self.code_length,
+            self.message_length,
+            self.minimum_distance(),
+        )
+    }
+}
+
+impl Decoder<CyclicCode> for CyclicCode {
+    fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        CyclicCode::decode(self, received)
+    }
+}
+
+/// `CyclicCode::decode_meggitt` as a `Decoder`, usable wherever the table-lookup decoder of
+/// the code is.
+#[derive(Debug, Clone)]
+pub struct MeggittDecoder {
+    code: CyclicCode,
+}
+
+impl MeggittDecoder {
+    pub fn new(code: CyclicCode) -> Self {
+        MeggittDecoder { code }
+    }
+}
+
+impl Decoder<CyclicCode> for MeggittDecoder {
+    fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        self.code.decode_meggitt(received)
+    }
+}
+
+// Maps syndromes to their minimum-weight error pattern for all patterns of weight <= t,
+// where t is the largest weight that keeps every syndrome distinct
+fn syndrome_table(generator_poly: PolyGF2, code_length: usize) -> (usize, BTreeMap<u32, u32>) {
+    let syndrome_count = 1u64 << generator_poly.degree();
+    let mut table = BTreeMap::from([(0, 0)]);
+    let mut patterns_of_weight = 1u64;
+
+    for weight in 1..=code_length {
+        patterns_of_weight = patterns_of_weight * (code_length - weight + 1) as u64 / weight as u64;
+        if table.len() as u64 + patterns_of_weight > syndrome_count {
+            return (weight - 1, table);
+        }
+
+        let mut leaders = BTreeMap::new();
+        let mut pattern = (1u32 << weight) - 1;
+        while pattern >> code_length == 0 {
+            let syndrome = (PolyGF2::new(pattern) % generator_poly).poly;
+            if table.contains_key(&syndrome) || leaders.insert(syndrome, pattern).is_some() {
+                return (weight - 1, table);
+            }
+            pattern = next_combination(pattern);
+        }
+        table.append(&mut leaders);
+    }
+    (code_length, table)
+}
+
+// Next larger integer with the same number of set bits (Gosper's hack)
+pub(crate) fn next_combination(x: u32) -> u32 {
+    let lowest = x & x.wrapping_neg();
+    let ripple = x.wrapping_add(lowest);
+    (((ripple ^ x) >> 2) / lowest) | ripple
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BCH;
+
+    #[test]
+    fn test_hamming_7_4() {
+        // x^7 - 1 = (x + 1)(x^3 + x + 1)(x^3 + x^2 + 1)
+        let code = CyclicCode::new(PolyGF2::new(0b1011), 7).unwrap();
+        assert_eq!(code.parameters(), (7, 4, 3));
+        for message in 0..16 {
+            let message = PolyGF2::new(message);
+            let encoded = code.encode(message).unwrap();
+            assert!(code.syndrome(encoded).is_zero());
+            assert_eq!(code.decode(encoded).unwrap().message(), &message);
+            for i in 0..7 {
+                let received = PolyGF2::new(encoded.poly ^ 1 << i);
+                assert_eq!(code.decode(received).unwrap().message(), &message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_repetition() {
+        let code = CyclicCode::new(PolyGF2::new(0b1111111), 7).unwrap();
+        assert_eq!(code.parameters(), (7, 1, 7));
+        let encoded = code.encode(PolyGF2::new(1)).unwrap();
+        assert_eq!(encoded, PolyGF2::new(0b1111111));
+        assert_eq!(
+            code.decode(PolyGF2::new(0b0101011)).unwrap().message(),
+            &PolyGF2::new(1)
+        );
+    }
+
+    #[test]
+    fn test_matches_bch() {
+        let bch = BCH::<4>::from_distance(7).unwrap();
+        let code = CyclicCode::new(bch.generator_poly(), 15).unwrap();
+        assert_eq!(code.parameters(), (15, 5, 7));
+        assert_eq!(code.max_errors(), 3);
+        let message = PolyGF2::new(0b11011);
+        let encoded = code.encode(message).unwrap();
+        assert_eq!(Ok(encoded), bch.encode(message));
+        let received = PolyGF2::new(encoded.poly ^ 0b10010000100000);
+        assert_eq!(code.decode(received).unwrap().message(), &message);
+    }
+
+    #[test]
+    fn test_minimum_distance() {
+        // Even-weight code, found among the low-weight patterns
+        let parity = CyclicCode::new(PolyGF2::new(0b11), 31).unwrap();
+        assert_eq!(parity.minimum_distance(), 2);
+
+        // The dual of the (31, 26) Hamming code is the simplex code, every nonzero codeword
+        // has weight 16. Its 32 codewords are enumerated instead of the patterns
+        let simplex = BCH::<5>::from_distance(3).unwrap().dual();
+        assert_eq!(simplex.parameters(), (31, 5, 16));
+        assert!(simplex.syndrome_table.get().is_none());
+    }
+
+    #[test]
+    fn test_meggitt() {
+        let code = CyclicCode::new(PolyGF2::new(0b1011), 7).unwrap();
+        for message in 0..16 {
+            let encoded = code.encode(PolyGF2::new(message)).unwrap();
+            assert_eq!(
+                code.decode_meggitt(encoded).unwrap().message(),
+                &PolyGF2::new(message)
+            );
+            for i in 0..7 {
+                let received = PolyGF2::new(encoded.poly ^ 1 << i);
+                assert_eq!(code.decode_meggitt(received), code.decode(received));
+            }
+        }
+
+        // Double errors against the algebraic BCH decoder
+        let bch = BCH::<4>::from_distance(5).unwrap();
+        let code = CyclicCode::new(bch.generator_poly(), 15).unwrap();
+        let encoded = code.encode(PolyGF2::new(0b1100101)).unwrap();
+        for a in 0..15 {
+            for b in a + 1..15 {
+                let received = PolyGF2::new(encoded.poly ^ 1 << a ^ 1 << b);
+                assert_eq!(
+                    code.decode_meggitt(received).unwrap().message(),
+                    &PolyGF2::new(0b1100101)
+                );
+                let algebraic = bch.decode_shortened(received, 0);
+                assert_eq!(code.decode_meggitt(received), algebraic);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_generator() {
+        assert!(CyclicCode::new(PolyGF2::new(0b101), 7).is_err());
+        assert!(CyclicCode::new(PolyGF2::new(0), 7).is_err());
+        assert!(CyclicCode::new(PolyGF2::new(0b1011), 32).is_err());
+        let code = CyclicCode::new(PolyGF2::new(0b1011), 7).unwrap();
+        assert_eq!(
+            code.encode(PolyGF2::new(0b10000)),
+            Err(CodingError::MessageTooLong)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{CodingError, DecodeResult, Decoder, Encoder};
+use alloc::vec;
+use galois::{Matrix, PolyGF2, GF2TM};
+
+/// The binary `(2^M - 1, 2^M - 1 - M)` Hamming code correcting a single error.
+///
+/// Bit `j` of a codeword sits at position `j + 1`. The parity bits occupy the power-of-two
+/// positions, so the syndrome of a word with one flipped bit is the position of that bit.
+#[derive(Debug, Clone, Copy)]
+pub struct HammingCode<const M: u32> {
+    code_length: usize,
+    message_length: usize,
+}
+
+impl<const M: u32> HammingCode<M> {
+    pub fn new() -> Result<Self, CodingError> {
+        if !(2..=5).contains(&M) {
+            return Err(CodingError::InvalidParameters(
+                "Hamming codes are supported for 2 <= M <= 5",
+            ));
+        }
+        let code_length = (1 << M) - 1;
+        Ok(HammingCode {
+            code_length,
+            message_length: code_length - M as usize,
+        })
+    }
+
+    pub fn code_length(&self) -> usize {
+        self.code_length
+    }
+
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    pub fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        if message.poly >> self.message_length != 0 {
+            return Err(CodingError::MessageTooLong);
+        }
+
+        // Spread the message over the positions that are not powers of two
+        let mut codeword = 0;
+        let mut i = 0;
+        for position in 1..=self.code_length {
+            if !position.is_power_of_two() {
+                codeword |= (message.poly >> i & 1) << (position - 1);
+                i += 1;
+            }
+        }
+
+        // Each parity bit cancels its own bit of the syndrome
+        let syndrome = self.syndrome(PolyGF2::new(codeword));
+        for k in 0..M {
+            codeword |= (syndrome >> k & 1) << ((1 << k) - 1);
+        }
+        Ok(PolyGF2::new(codeword))
+    }
+
+    /// Corrects a single error and returns the message together with the corrected
+    /// position, if any.
+    pub fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length != 0 {
+            return Err(CodingError::WrongLength);
+        }
+
+        let mut corrected = received.poly;
+        let syndrome = self.syndrome(received);
+        let mut error_positions = vec![];
+        if syndrome != 0 {
+            corrected ^= 1 << (syndrome - 1);
+            error_positions.push(syndrome as usize - 1);
+        }
+
+        let mut message = 0;
+        let mut i = 0;
+        for position in 1..=self.code_length {
+            if !position.is_power_of_two() {
+                message |= (corrected >> (position - 1) & 1) << i;
+                i += 1;
+            }
+        }
+        Ok(DecodeResult::new(PolyGF2::new(message), error_positions, 0))
+    }
+
+    /// XOR of the positions of all set bits; zero exactly for codewords.
+    pub fn syndrome(&self, received: PolyGF2) -> u32 {
+        (0..self.code_length)
+            .filter(|j| received.poly >> j & 1 == 1)
+            .fold(0, |acc, j| acc ^ (j as u32 + 1))
+    }
+
+    pub fn parity_check_matrix(&self) -> Matrix<GF2TM<M>> {
+        // M x n binary matrix whose column j is the binary expansion of j + 1,
+        // least significant bit in the first row
+        let mut matrix = Matrix::zero(M as usize, self.code_length);
+        for j in 0..self.code_length {
+            for i in 0..M as usize {
+                matrix[[i, j]] = GF2TM::from((j as u32 + 1) >> i & 1);
+            }
+        }
+        matrix
+    }
+}
+
+impl<const M: u32> Encoder for HammingCode<M> {
+    type Message = PolyGF2;
+    type Codeword = PolyGF2;
+
+    fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        HammingCode::encode(self, message)
+    }
+
+    fn parameters(&self) -> (usize, usize, usize) {
+        (self.code_length, self.message_length, 3)
+    }
+}
+
+impl<const M: u32> Decoder<HammingCode<M>> for HammingCode<M> {
+    fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        HammingCode::decode(self, received)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+
+    fn check_single_errors<const M: u32>() {
+        let hamming = HammingCode::<M>::new().unwrap();
+        let n = hamming.code_length();
+        let parity_check = hamming.parity_check_matrix();
+        for message in [0, 1, 0b1011, (1 << hamming.message_length()) - 1] {
+            let message = PolyGF2::new(message);
+            let encoded = hamming.encode(message).unwrap();
+            assert_eq!(hamming.syndrome(encoded), 0);
+
+            let codeword: Vec<_> = (0..n)
+                .map(|j| GF2TM::<M>::from(encoded.poly >> j & 1))
+                .collect();
+            assert!(parity_check.vec_mul(&codeword).iter().all(|s| s.is_zero()));
+
+            assert_eq!(hamming.decode(encoded).unwrap().message(), &message);
+            for j in 0..n {
+                let received = PolyGF2::new(encoded.poly ^ 1 << j);
+                assert_eq!(hamming.syndrome(received), j as u32 + 1);
+                assert_eq!(hamming.decode(received).unwrap().message(), &message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hamming_7_4() {
+        let hamming = HammingCode::<3>::new().unwrap();
+        assert_eq!(hamming.parameters(), (7, 4, 3));
+        // Data bits 1, 0, 1, 1 land at positions 3, 5, 6, 7, then p1 = 0, p2 = 1, p4 = 0
+        assert_eq!(
+            hamming.encode(PolyGF2::new(0b1101)),
+            Ok(PolyGF2::new(0b1100110))
+        );
+        check_single_errors::<3>();
+    }
+
+    #[test]
+    fn test_hamming_15_11() {
+        let hamming = HammingCode::<4>::new().unwrap();
+        assert_eq!(hamming.parameters(), (15, 11, 3));
+        check_single_errors::<4>();
+    }
+
+    #[test]
+    fn test_hamming_errors() {
+        assert!(HammingCode::<1>::new().is_err());
+        let hamming = HammingCode::<3>::new().unwrap();
+        assert_eq!(
+            hamming.encode(PolyGF2::new(0b10000)),
+            Err(CodingError::MessageTooLong)
+        );
+        assert_eq!(
+            hamming.decode(PolyGF2::new(1 << 7)),
+            Err(CodingError::WrongLength)
+        );
+    }
+}
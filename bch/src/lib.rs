@@ -1,85 +1,605 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+pub use galois::CodingError;
 use galois::{PolyGF2, GF2TM};
-use polynomial::Polynomial;
 
 use galois::Matrix;
 use num_traits::Zero;
 
+pub mod bounds;
+mod channel;
+mod crc;
+mod cyclic;
+mod hamming;
+mod standard_array;
+
+pub use crate::channel::inject_errors;
+#[cfg(feature = "rand")]
+pub use crate::channel::{inject_random_errors, BinaryErasureChannel, BinarySymmetricChannel};
+pub use crate::crc::Crc;
+pub use crate::cyclic::{CyclicCode, MeggittDecoder};
+pub use crate::hamming::HammingCode;
+pub use crate::standard_array::{StandardArrayDecoder, STANDARD_ARRAY_MAX_REDUNDANCY};
+
+/// How the decoder derives the error-locator polynomial from the syndromes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LocatorAlgorithm {
+    /// Solve the Newton identities as a linear system for every candidate error count, O(t^4).
+    LinearSystem,
+    /// Berlekamp–Massey over the syndrome sequence, O(t^2).
+    #[default]
+    BerlekampMassey,
+    /// Extended Euclidean algorithm on the key equation, see `solve_key_equation`, O(t^2).
+    Euclidean,
+}
+
+/// Encoding half of a block code, so that transmitters do not depend on any decoder.
+pub trait Encoder {
+    type Message;
+    type Codeword;
+
+    fn encode(&self, message: Self::Message) -> Result<Self::Codeword, CodingError>;
+
+    /// Code length `n`, dimension `k` and (designed) minimum distance `d`.
+    fn parameters(&self) -> (usize, usize, usize);
+
+    /// Lazily encodes a stream of messages, one result per block.
+    fn encode_blocks<I>(
+        &self,
+        messages: I,
+    ) -> impl Iterator<Item = Result<Self::Codeword, CodingError>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Message>,
+    {
+        messages
+            .into_iter()
+            .map(move |message| self.encode(message))
+    }
+
+    /// Encodes many messages at once, failing if any of them cannot be encoded. Codes
+    /// override it to share work between blocks or to spread them over threads.
+    fn encode_batch(&self, messages: &[Self::Message]) -> Result<Vec<Self::Codeword>, CodingError>
+    where
+        Self::Message: Clone,
+    {
+        messages
+            .iter()
+            .map(|message| self.encode(message.clone()))
+            .collect()
+    }
+}
+
+/// A decoder for the words produced by the encoder `C`. Every code decodes its own words,
+/// and alternative algorithms for the same code, such as `MeggittDecoder`, are separate impls.
+pub trait Decoder<C: Encoder + ?Sized> {
+    fn decode(&self, received: C::Codeword) -> Result<DecodeResult<C::Message>, CodingError>;
+
+    /// Lazily decodes a stream of received words, one result per block, so a single
+    /// uncorrectable block does not stop the rest of the stream.
+    fn decode_blocks<I>(
+        &self,
+        received: I,
+    ) -> impl Iterator<Item = Result<DecodeResult<C::Message>, CodingError>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = C::Codeword>,
+    {
+        received
+            .into_iter()
+            .map(move |received| self.decode(received))
+    }
+
+    /// Decodes many received words at once, with one result per word as in `decode_blocks`.
+    fn decode_batch(
+        &self,
+        received: &[C::Codeword],
+    ) -> Vec<Result<DecodeResult<C::Message>, CodingError>>
+    where
+        C::Codeword: Clone,
+    {
+        received
+            .iter()
+            .map(|received| self.decode(received.clone()))
+            .collect()
+    }
+}
+
+/// Common interface of the block codes in this crate, so that simulations can be
+/// written once for any of them. Implemented by every code that decodes its own words.
+pub trait LinearCode: Encoder + Decoder<Self> {}
+
+impl<C: Encoder + Decoder<C>> LinearCode for C {}
+
+/// Largest message length for which `BCH::minimum_distance_bruteforce` and
+/// `BCH::weight_distribution` enumerate codewords.
+pub const BRUTE_FORCE_MAX_MESSAGE_LENGTH: usize = 20;
+
+/// Outcome of every decoder: the message together with what the decoder
+/// had to correct. Positions are indices into the received word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeResult<T = PolyGF2> {
+    pub message: T,
+    pub error_positions: Vec<usize>,
+    pub num_errors: usize,
+    pub num_erasures: usize,
+}
+
+impl<T> DecodeResult<T> {
+    fn new(message: T, error_positions: Vec<usize>, num_erasures: usize) -> Self {
+        DecodeResult {
+            message,
+            num_errors: error_positions.len(),
+            error_positions,
+            num_erasures,
+        }
+    }
+
+    pub fn message(&self) -> &T {
+        &self.message
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BCH<const M: u32> {
     primitive_element: GF2TM<M>,
     distance: usize,
+    first_root: usize,
     code_length: usize,
     message_length: usize,
     generator_poly: PolyGF2,
+    locator_algorithm: LocatorAlgorithm,
 }
 
 impl<const M: u32> BCH<M> {
-    pub fn from_distance(distance: usize) -> Result<Self, &'static str> {
-        Self::from_max_errors((distance - 1) / 2).map_err(|_| "Max allowed distance is M - 1")
+    pub fn from_distance(distance: usize) -> Result<Self, CodingError> {
+        if distance < 3 {
+            return Err(CodingError::InvalidParameters(
+                "Distance should be at least 3",
+            ));
+        }
+        if distance >= 2_usize.pow(M) {
+            return Err(CodingError::InvalidParameters(
+                "Max allowed distance is 2^M - 1",
+            ));
+        }
+        Self::from_max_errors((distance - 1) / 2)
     }
 
-    pub fn from_max_errors(max_errors: usize) -> Result<Self, &'static str> {
+    pub fn from_max_errors(max_errors: usize) -> Result<Self, CodingError> {
+        if max_errors == 0 {
+            return Err(CodingError::InvalidParameters(
+                "Max allowed errors should be at least 1",
+            ));
+        }
         let distance = 2 * max_errors + 1;
         if distance >= 2_usize.pow(M) {
-            return Err("Max allowed errors should be < (2^M - 1) / 2");
+            return Err(CodingError::InvalidParameters(
+                "Max allowed errors should be < (2^M - 1) / 2",
+            ));
+        }
+        Self::from_params(1, distance)
+    }
+
+    /// General BCH code whose generator has the `delta - 1` consecutive roots
+    /// α^b, α^(b + 1), ..., α^(b + delta - 2). `b = 1` gives the narrow-sense code.
+    /// Fails for M > 5, whose 2^M - 1 bit codewords do not fit into a `PolyGF2`.
+    pub fn from_params(b: usize, delta: usize) -> Result<Self, CodingError> {
+        if 2_usize.pow(M) - 1 > 31 {
+            return Err(CodingError::InvalidParameters(
+                "Code length 2^M - 1 should be at most 31",
+            ));
+        }
+        if delta < 2 {
+            return Err(CodingError::InvalidParameters(
+                "Designed distance should be at least 2",
+            ));
+        }
+        if delta >= 2_usize.pow(M) {
+            return Err(CodingError::InvalidParameters(
+                "Designed distance should be < 2^M",
+            ));
         }
 
         let primitive_element = GF2TM::<M>::primitive_element();
         let code_length = 2_usize.pow(M) - 1;
 
-        let generator_poly = (1..distance)
+        let generator_poly = (b..b + delta - 1)
             .map(|i| primitive_element.pow(i as u32).minimal_poly())
             .reduce(|acc, e| acc.lcm(e))
             .unwrap();
         let message_length = code_length - generator_poly.degree();
         Ok(BCH {
             primitive_element,
-            distance,
+            distance: delta,
+            first_root: b,
             code_length,
             message_length,
             generator_poly,
+            locator_algorithm: LocatorAlgorithm::default(),
         })
     }
 
-    pub fn encode(&self, message: PolyGF2) -> Result<PolyGF2, &'static str> {
-        let message_length = message.degree() + 1;
-        if message_length > self.message_length {
-            return Err("Message is too long");
+    pub fn with_locator_algorithm(self, locator_algorithm: LocatorAlgorithm) -> Self {
+        BCH {
+            locator_algorithm,
+            ..self
+        }
+    }
+
+    /// Systematic encoding: the message of degree below `message_length` occupies the top
+    /// positions of the codeword, the parity bits the bottom ones.
+    pub fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        self.encode_shortened(message, 0)
+    }
+
+    /// Corrects up to `(distance - 1) / 2` bit errors and returns the message together
+    /// with the corrected positions.
+    pub fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length != 0 {
+            return Err(CodingError::WrongLength);
+        }
+
+        let (corrected, error_positions) = self.correct(received)?;
+        Ok(DecodeResult::new(
+            PolyGF2::new(corrected.poly >> self.generator_poly.degree()),
+            error_positions,
+            0,
+        ))
+    }
+
+    /// Encodes a message of at most `message_length - shorten` bits into the
+    /// `(code_length - shorten, message_length - shorten)` shortened code.
+    /// The top `shorten` message positions are fixed to zero and never transmitted.
+    pub fn encode_shortened(
+        &self,
+        message: PolyGF2,
+        shorten: usize,
+    ) -> Result<PolyGF2, CodingError> {
+        if shorten >= self.message_length {
+            return Err(CodingError::InvalidParameters(
+                "Cannot shorten by the whole message length",
+            ));
+        }
+        if message.poly >> (self.message_length - shorten) != 0 {
+            return Err(CodingError::MessageTooLong);
+        }
+        let shifted = message * PolyGF2::new(1 << self.generator_poly.degree());
+        Ok(shifted + shifted % self.generator_poly)
+    }
+
+    pub fn decode_shortened(
+        &self,
+        received: PolyGF2,
+        shorten: usize,
+    ) -> Result<DecodeResult, CodingError> {
+        if shorten >= self.message_length {
+            return Err(CodingError::InvalidParameters(
+                "Cannot shorten by the whole message length",
+            ));
+        }
+        if received.poly >> (self.code_length - shorten) != 0 {
+            return Err(CodingError::WrongLength);
+        }
+
+        // The omitted positions are known zeros, so an error located there means
+        // the word was decoded to the wrong codeword
+        let (corrected, error_positions) = self.correct(received)?;
+        if error_positions
+            .iter()
+            .any(|&position| position >= self.code_length - shorten)
+        {
+            return Err(CodingError::Uncorrectable);
+        }
+        Ok(DecodeResult::new(
+            PolyGF2::new(corrected.poly >> self.generator_poly.degree()),
+            error_positions,
+            0,
+        ))
+    }
+
+    /// Encodes an arbitrary byte string. The data is prefixed with its length as a
+    /// little-endian `u32` and split into `message_length`-bit messages, zero-padding the
+    /// last one. The `code_length`-bit codewords are packed least significant bit first.
+    /// Fails with `MessageTooLong` if the length does not fit into the `u32` header.
+    pub fn encode_bytes(&self, data: &[u8]) -> Result<Vec<u8>, CodingError> {
+        let length = u32::try_from(data.len()).map_err(|_| CodingError::MessageTooLong)?;
+        let mut stream = length.to_le_bytes().to_vec();
+        stream.extend_from_slice(data);
+
+        let messages = (0..stream.len() * 8)
+            .step_by(self.message_length)
+            .map(|offset| read_bits(&stream, offset, self.message_length))
+            .collect();
+        let codewords = map_blocks(messages, |message| {
+            self.encode_shortened(PolyGF2::new(message), 0).unwrap()
+        });
+
+        let mut output = BitWriter::default();
+        for codeword in codewords {
+            output.write(codeword.poly, self.code_length);
+        }
+        Ok(output.bytes)
+    }
+
+    /// Inverse of `encode_bytes`, correcting up to `(distance - 1) / 2` bit errors
+    /// in every codeword.
+    pub fn decode_bytes(&self, data: &[u8]) -> Result<Vec<u8>, CodingError> {
+        let received = (0..data.len() * 8 / self.code_length)
+            .map(|i| read_bits(data, i * self.code_length, self.code_length))
+            .collect();
+        let corrected = map_blocks(received, |received| self.correct(PolyGF2::new(received)));
+
+        let mut stream = BitWriter::default();
+        for result in corrected {
+            let (corrected, _) = result?;
+            stream.write(
+                corrected.poly >> self.generator_poly.degree(),
+                self.message_length,
+            );
+        }
+
+        let stream = stream.bytes;
+        if stream.len() < 4 {
+            return Err(CodingError::WrongLength);
+        }
+        let length = u32::from_le_bytes([stream[0], stream[1], stream[2], stream[3]]) as usize;
+        if stream.len() - 4 < length {
+            return Err(CodingError::Uncorrectable);
+        }
+        Ok(stream[4..4 + length].to_vec())
+    }
+
+    /// Builds a punctured code that does not transmit the given parity positions.
+    pub fn puncture(&self, positions: &[usize]) -> Result<PuncturedBCH<M>, CodingError> {
+        let mut positions = positions.to_vec();
+        positions.sort_unstable();
+        positions.dedup();
+        if positions
+            .iter()
+            .any(|&position| position >= self.generator_poly.degree())
+        {
+            return Err(CodingError::InvalidParameters(
+                "Only parity positions can be punctured",
+            ));
+        }
+        if positions.len() + 1 >= self.distance {
+            return Err(CodingError::InvalidParameters(
+                "Too many punctured positions",
+            ));
         }
-        let padded = message * PolyGF2::new(1 << (self.code_length - message_length));
-        let remainder = padded % self.generator_poly;
-        Ok(padded - remainder)
+        Ok(PuncturedBCH {
+            bch: *self,
+            positions,
+        })
     }
 
-    pub fn decode(&self, received: PolyGF2) -> Result<PolyGF2, &'static str> {
-        let received_length = received.degree() + 1;
-        if received_length != self.code_length {
-            return Err("Received message has wrong length");
+    fn correct(&self, received: PolyGF2) -> Result<(PolyGF2, Vec<usize>), CodingError> {
+        let syndromes = self.syndromes(received);
+        let (_, error_positions) = self.locate_errors(&syndromes)?;
+
+        // Binary errors always have magnitude 1, so the positions alone are enough
+        let error = error_positions.iter().fold(0, |acc, e| acc ^ (1u32 << e));
+        let corrected = PolyGF2::new(received.poly ^ error);
+        if !self.is_codeword(corrected) {
+            return Err(CodingError::Uncorrectable);
         }
+        Ok((corrected, error_positions))
+    }
 
+    /// Evaluates the received word at the `distance - 1` consecutive roots α^b, α^(b + 1), ...
+    /// of the generator polynomial. All syndromes vanish exactly for codewords.
+    pub fn syndromes(&self, received: PolyGF2) -> Vec<GF2TM<M>> {
         let mut received_poly_gf2 = received.poly;
         let mut coefficients = vec![];
         for _ in 0..self.code_length {
             coefficients.push(GF2TM::<M>::from(received_poly_gf2 & 1));
             received_poly_gf2 >>= 1;
         }
-        let received_poly_gf2m = Polynomial::new(coefficients);
-        let syndromes: Vec<_> = (1..self.distance)
-            .map(|i| received_poly_gf2m.eval(self.primitive_element.pow(i as u32)))
+        self.symbol_syndromes(&coefficients)
+    }
+
+    /// Checks whether `received` is a codeword without attempting any correction.
+    pub fn is_codeword(&self, received: PolyGF2) -> bool {
+        received.poly >> self.code_length == 0
+            && self.syndromes(received).iter().all(|s| s.is_zero())
+    }
+
+    /// The message of a systematic codeword, as produced by `encode_shortened` or
+    /// `LinearCode::encode`, read off its top `message_length` positions without decoding.
+    pub fn message_part(&self, codeword: PolyGF2) -> PolyGF2 {
+        PolyGF2::new(codeword.poly >> self.generator_poly.degree())
+    }
+
+    /// The parity bits of a systematic codeword, its bottom `code_length - message_length`
+    /// positions.
+    pub fn parity_part(&self, codeword: PolyGF2) -> PolyGF2 {
+        PolyGF2::new(codeword.poly & ((1 << self.generator_poly.degree()) - 1))
+    }
+
+    /// Reports whether `received` was corrupted, the opposite of `is_codeword`.
+    pub fn detect_errors(&self, received: PolyGF2) -> bool {
+        !self.is_codeword(received)
+    }
+
+    /// Systematically encodes `message_length` symbols over GF(2^M), lowest degree first.
+    /// The message occupies the top positions of the codeword, the parity symbols the bottom ones.
+    pub fn encode_symbols(&self, message: &[GF2TM<M>]) -> Result<Vec<GF2TM<M>>, CodingError> {
+        if message.len() != self.message_length {
+            return Err(CodingError::WrongLength);
+        }
+
+        let parity_length = self.generator_poly.degree();
+        let generator: Vec<_> = (0..=parity_length)
+            .map(|i| GF2TM::<M>::from(self.generator_poly.poly >> i & 1))
             .collect();
+        let mut codeword = vec![GF2TM::<M>::zero(); self.code_length];
+        codeword[parity_length..].copy_from_slice(message);
 
-        let error = if let Some(error_locator) = self.error_locator(syndromes) {
-            let error_positions = self.chien_search(error_locator);
-            let error_values = error_positions.iter().fold(0, |acc, e| acc ^ (1u32 << e));
-            error_values
-        } else {
-            0
-        };
-        let corrected = received.poly + error;
-        Ok(PolyGF2::new(corrected >> self.generator_poly.degree()))
+        // Long division of x^(n - k) * m(x) by the monic generator polynomial
+        let mut remainder = codeword.clone();
+        for i in (parity_length..self.code_length).rev() {
+            let coefficient = remainder[i];
+            for (j, g) in generator.iter().enumerate() {
+                remainder[i - parity_length + j] -= coefficient * *g;
+            }
+        }
+        for i in 0..parity_length {
+            codeword[i] = -remainder[i];
+        }
+        Ok(codeword)
+    }
+
+    /// Corrects up to `(distance - 1) / 2` symbol errors of arbitrary magnitude in a word
+    /// produced by `encode_symbols` and returns the message symbols together with the
+    /// corrected positions.
+    pub fn decode_symbols(
+        &self,
+        received: &[GF2TM<M>],
+    ) -> Result<DecodeResult<Vec<GF2TM<M>>>, CodingError> {
+        if received.len() != self.code_length {
+            return Err(CodingError::WrongLength);
+        }
+
+        let syndromes = self.symbol_syndromes(received);
+        let (error_locator, error_positions) = self.locate_errors(&syndromes)?;
+        let error_evaluator = self.error_evaluator(&syndromes, &error_locator);
+        let error_magnitudes =
+            self.error_magnitudes(&error_locator, &error_evaluator, &error_positions);
+        let mut corrected = received.to_vec();
+        for (&position, magnitude) in error_positions.iter().zip(error_magnitudes) {
+            corrected[position] -= magnitude;
+        }
+        if !self
+            .symbol_syndromes(&corrected)
+            .iter()
+            .all(|s| s.is_zero())
+        {
+            return Err(CodingError::Uncorrectable);
+        }
+        Ok(DecodeResult::new(
+            corrected[self.generator_poly.degree()..].to_vec(),
+            error_positions,
+            0,
+        ))
+    }
+
+    /// Syndromes of a word over GF(2^M), lowest degree first; see `syndromes`.
+    pub fn symbol_syndromes(&self, received: &[GF2TM<M>]) -> Vec<GF2TM<M>> {
+        // Horner's rule for all roots α^b, ..., α^(b + d - 2) at once, in one pass over `received`
+        let mut roots = Vec::with_capacity(self.distance - 1);
+        let mut root = self.primitive_element.pow(self.first_root as u32);
+        for _ in 1..self.distance {
+            roots.push(root);
+            root *= self.primitive_element;
+        }
+
+        let mut syndromes = vec![GF2TM::zero(); roots.len()];
+        for coefficient in received.iter().rev() {
+            for (syndrome, root) in syndromes.iter_mut().zip(&roots) {
+                *syndrome = *syndrome * *root + *coefficient;
+            }
+        }
+        syndromes
+    }
+
+    /// Error evaluator Ω(x) = S(x) Λ(x) mod x^(d - 1), where S(x) = S_b + S_(b + 1) x + ...
+    /// and Λ(x) = Π(1 - X_k x) is the reversal of `error_locator`.
+    pub fn error_evaluator(
+        &self,
+        syndromes: &[GF2TM<M>],
+        error_locator: &[GF2TM<M>],
+    ) -> Vec<GF2TM<M>> {
+        key_equation_evaluator(syndromes, &connection_poly(error_locator), syndromes.len())
+    }
+
+    /// Full solution of the key equation for a word over GF(2^M), see `solve_key_equation`:
+    /// the error locator σ(x) with σ(0) = 1 and the error evaluator ω(x), lowest degree first.
+    /// Meant for inspecting the decoder step by step, `decode_symbols` does not need it.
+    pub fn locator_and_evaluator(
+        &self,
+        received: &[GF2TM<M>],
+    ) -> Result<(Vec<GF2TM<M>>, Vec<GF2TM<M>>), CodingError> {
+        if received.len() != self.code_length {
+            return Err(CodingError::WrongLength);
+        }
+        let syndromes = self.symbol_syndromes(received);
+        solve_key_equation(&syndromes, syndromes.len() / 2).ok_or(CodingError::Uncorrectable)
+    }
+
+    /// Forney algorithm: the value to subtract at each of `error_positions`, which is
+    /// -X_k^(1 - b) Ω(X_k^-1) / Λ'(X_k^-1) for the error locator X_k = α^position.
+    pub fn error_magnitudes(
+        &self,
+        error_locator: &[GF2TM<M>],
+        error_evaluator: &[GF2TM<M>],
+        error_positions: &[usize],
+    ) -> Vec<GF2TM<M>> {
+        let derivative: Vec<_> = connection_poly(error_locator)
+            .into_iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| if i % 2 == 1 { c } else { GF2TM::zero() })
+            .collect();
+
+        error_positions
+            .iter()
+            .map(|&position| {
+                let x = self.primitive_element.pow(position as u32);
+                let x_inv = x.inv();
+                // X_k^(1 - b), using X_k^n = 1 to keep the exponent non-negative
+                let n = self.code_length;
+                let offset = x.pow(((n + 1 - self.first_root % n) % n) as u32);
+                -(offset * poly_eval(error_evaluator, x_inv) / poly_eval(&derivative, x_inv))
+            })
+            .collect()
+    }
+
+    fn locate_errors(
+        &self,
+        syndromes: &[GF2TM<M>],
+    ) -> Result<(Vec<GF2TM<M>>, Vec<usize>), CodingError> {
+        if syndromes.iter().all(|s| s.is_zero()) {
+            return Ok((vec![GF2TM::one()], vec![]));
+        }
+
+        // With more than t errors the locator either does not exist, is too long, or does
+        // not split into distinct roots among the code positions
+        let error_locator = self
+            .error_locator(syndromes)
+            .ok_or(CodingError::Uncorrectable)?;
+        let degree = error_locator.len() - 1;
+        let error_positions = self.chien_search(&error_locator);
+        if degree > syndromes.len() / 2 || error_positions.len() != degree {
+            return Err(CodingError::Uncorrectable);
+        }
+        Ok((error_locator, error_positions))
+    }
+
+    /// Error-locator polynomial for the given syndromes, lowest degree first, normalized to
+    /// be monic with the error locators α^position themselves as roots.
+    /// `None` if no locator was found.
+    pub fn error_locator(&self, syndromes: &[GF2TM<M>]) -> Option<Vec<GF2TM<M>>> {
+        match self.locator_algorithm {
+            LocatorAlgorithm::LinearSystem => self.linear_system_locator(syndromes),
+            LocatorAlgorithm::BerlekampMassey => {
+                let mut locator = berlekamp_massey(syndromes);
+                locator.reverse();
+                Some(locator)
+            }
+            LocatorAlgorithm::Euclidean => {
+                let (mut locator, _) = solve_key_equation(syndromes, syndromes.len() / 2)?;
+                locator.reverse();
+                Some(locator)
+            }
+        }
     }
 
-    fn error_locator(&self, syndromes: Vec<GF2TM<M>>) -> Option<Polynomial<GF2TM<M>>> {
+    fn linear_system_locator(&self, syndromes: &[GF2TM<M>]) -> Option<Vec<GF2TM<M>>> {
         let t = syndromes.len() / 2;
         for v in (1..=t).rev() {
             let mut matrix = Matrix::<GF2TM<M>>::zero(v, v);
@@ -96,77 +616,1530 @@ impl<const M: u32> BCH<M> {
                 .collect::<Vec<GF2TM<M>>>();
             if let Some(mut solution) = matrix.solve(right_part) {
                 solution.push(GF2TM::<M>::one());
-                return Some(Polynomial::new(solution));
+                return Some(solution);
             }
         }
         None
     }
 
-    fn chien_search(&self, error_locator: Polynomial<GF2TM<M>>) -> Vec<usize> {
-        (0..self.code_length)
-            .filter(|i| {
-                error_locator
-                    .eval(self.primitive_element.pow(*i as u32))
-                    .is_zero()
-            })
-            .collect()
+    /// Positions `i` for which α^i is a root of `error_locator`.
+    pub fn chien_search(&self, error_locator: &[GF2TM<M>]) -> Vec<usize> {
+        // The j-th term c_j α^(ij) is carried from position i to i + 1 by one multiplication
+        // with α^j, so no powers are recomputed along the way
+        let mut steps = Vec::with_capacity(error_locator.len());
+        let mut step = GF2TM::one();
+        for _ in error_locator {
+            steps.push(step);
+            step *= self.primitive_element;
+        }
+
+        let mut terms = error_locator.to_vec();
+        let mut roots = vec![];
+        for i in 0..self.code_length {
+            if terms
+                .iter()
+                .fold(GF2TM::zero(), |acc, term| acc + *term)
+                .is_zero()
+            {
+                roots.push(i);
+            }
+            for (term, step) in terms.iter_mut().zip(&steps) {
+                *term *= *step;
+            }
+        }
+        roots
     }
 
     pub fn max_message_length(&self) -> usize {
         self.message_length
     }
+
+    pub fn code_length(&self) -> usize {
+        self.code_length
+    }
+
+    pub fn message_length(&self) -> usize {
+        self.message_length
+    }
+
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    pub fn first_root(&self) -> usize {
+        self.first_root
+    }
+
+    pub fn generator_poly(&self) -> PolyGF2 {
+        self.generator_poly
+    }
+
+    /// Number of codewords of every Hamming weight `0..=code_length`, found by encoding
+    /// all `2^message_length` messages. Refuses codes with more than
+    /// `BRUTE_FORCE_MAX_MESSAGE_LENGTH` message bits.
+    pub fn weight_distribution(&self) -> Result<Vec<usize>, CodingError> {
+        if self.message_length > BRUTE_FORCE_MAX_MESSAGE_LENGTH {
+            return Err(CodingError::InvalidParameters(
+                "Message space is too large for brute force",
+            ));
+        }
+        let mut distribution = vec![0; self.code_length + 1];
+        for message in 0..1u32 << self.message_length {
+            let codeword = self.encode_shortened(PolyGF2::new(message), 0).unwrap();
+            distribution[codeword.weight() as usize] += 1;
+        }
+        Ok(distribution)
+    }
+
+    /// True minimum distance, i.e. the smallest weight of a nonzero codeword, found by
+    /// encoding every message. Refuses codes with more than
+    /// `BRUTE_FORCE_MAX_MESSAGE_LENGTH` message bits.
+    pub fn minimum_distance_bruteforce(&self) -> Result<usize, CodingError> {
+        if self.message_length > BRUTE_FORCE_MAX_MESSAGE_LENGTH {
+            return Err(CodingError::InvalidParameters(
+                "Message space is too large for brute force",
+            ));
+        }
+        Ok((1..1u32 << self.message_length)
+            .map(|message| {
+                let codeword = self.encode_shortened(PolyGF2::new(message), 0).unwrap();
+                codeword.weight() as usize
+            })
+            .min()
+            .unwrap_or(0))
+    }
+
+    pub fn generator_matrix(&self) -> Matrix<GF2TM<M>> {
+        // k x n matrix whose i-th row holds the coefficients of x^i * g(x), lowest degree first
+        let mut matrix = Matrix::zero(self.message_length, self.code_length);
+        for i in 0..self.message_length {
+            let row = self.generator_poly * PolyGF2::new(1 << i);
+            for j in 0..self.code_length {
+                matrix[[i, j]] = GF2TM::from(row.poly >> j & 1);
+            }
+        }
+        matrix
+    }
+
+    pub fn parity_check_matrix(&self) -> Matrix<GF2TM<M>> {
+        // (d - 1) x n matrix over GF(2^M) with α^(i * j) in row j - b and column i,
+        // one row per consecutive root α^j of the generator polynomial
+        let mut matrix = Matrix::zero(self.distance - 1, self.code_length);
+        for j in self.first_root..self.first_root + self.distance - 1 {
+            for i in 0..self.code_length {
+                matrix[[j - self.first_root, i]] = self.primitive_element.pow((i * j) as u32);
+            }
+        }
+        matrix
+    }
+
+    /// Binary generator matrix `[I | P]` with the message in the first `message_length`
+    /// positions, derived from the parity-check matrix. Every row of `parity_check_matrix`
+    /// is expanded into its `M` bit rows first, since its kernel over GF(2^M) is larger
+    /// than the binary code.
+    pub fn systematic_generator_matrix(&self) -> Matrix<GF2TM<M>> {
+        let parity_check = self.parity_check_matrix();
+        let mut binary = Matrix::zero(parity_check.rows() * M as usize, self.code_length);
+        for row in 0..parity_check.rows() {
+            for bit in 0..M as usize {
+                for i in 0..self.code_length {
+                    let value = parity_check[[row, i]].value().poly;
+                    binary[[row * M as usize + bit, i]] = GF2TM::from(value >> bit & 1);
+                }
+            }
+        }
+        // Any k consecutive positions of a cyclic code are an information set
+        binary.systematic_generator().unwrap()
+    }
+
+    /// The dual code C^⊥ = {y : Σ x_i y_i = 0 for all x in C}, of dimension `n - k`.
+    ///
+    /// The dual of a cyclic code is cyclic, generated by the reciprocal x^k h(1/x) of the
+    /// check polynomial h(x) = (x^n - 1) / g(x), so its parity-check matrix is the binary
+    /// generator matrix of this code and vice versa.
+    pub fn dual(&self) -> CyclicCode {
+        let n = self.code_length;
+        let k = self.message_length;
+        let check_poly = PolyGF2::new(1 << n | 1) / self.generator_poly;
+        let reciprocal = (0..=k).fold(0, |acc, i| acc | (check_poly.poly >> i & 1) << (k - i));
+        // The reciprocal of a divisor of x^n - 1 divides it as well
+        CyclicCode::new(PolyGF2::new(reciprocal), n).unwrap()
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use galois::PolyGF2;
+/// Interleaves `depth` BCH codewords column by column, so that a burst of up to
+/// `depth * t` consecutive channel errors leaves at most `t` errors in every codeword.
+#[derive(Debug, Clone, Copy)]
+pub struct InterleavedBCH<const M: u32> {
+    bch: BCH<M>,
+    depth: usize,
+}
 
-    #[test]
-    fn test_encode() {
-        const M: u32 = 4;
-        let bch = BCH::<M>::from_distance(7).unwrap();
-        let message = PolyGF2::new(0b11011);
-        let encoded = bch.encode(message);
-        assert_eq!(encoded, Ok(PolyGF2::new(0b110111000010100)));
+impl<const M: u32> InterleavedBCH<M> {
+    pub fn new(bch: BCH<M>, depth: usize) -> Result<Self, CodingError> {
+        if depth == 0 || depth > 32 {
+            return Err(CodingError::InvalidParameters(
+                "Interleaving depth should be between 1 and 32",
+            ));
+        }
+        Ok(InterleavedBCH { bch, depth })
     }
 
-    #[test]
-    fn test_decode_2_err() {
-        const M: u32 = 4;
-        let bch = BCH::<M>::from_distance(7).unwrap();
-        let message = PolyGF2::new(0b11011);
-        let encoded = bch.encode(message).unwrap();
-        let err = 0b10000000100000;
-        let received = PolyGF2::new(encoded.poly ^ err);
-        let decoded = bch.decode(received);
-        assert_eq!(decoded, Ok(message));
+    pub fn depth(&self) -> usize {
+        self.depth
     }
 
-    #[test]
-    fn test_decode_3_err() {
-        const M: u32 = 4;
-        let bch = BCH::<M>::from_max_errors(3).unwrap();
-        let message = PolyGF2::new(0b11011);
-        let encoded = bch.encode(message).unwrap();
-        let err = 0b10010000100000;
-        let received = PolyGF2::new(encoded.poly ^ err);
-        let decoded = bch.decode(received);
-        assert_eq!(decoded, Ok(message));
+    /// Encodes `depth` messages and returns the `code_length` columns to transmit in order.
+    /// Bit `j` of column `i` is bit `i` of the `j`-th codeword.
+    pub fn encode(&self, messages: &[PolyGF2]) -> Result<Vec<PolyGF2>, CodingError> {
+        if messages.len() != self.depth {
+            return Err(CodingError::WrongLength);
+        }
+        let codewords = map_blocks(messages.to_vec(), |message| {
+            self.bch.encode_shortened(message, 0)
+        })
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((0..self.bch.code_length)
+            .map(|i| {
+                codewords
+                    .iter()
+                    .enumerate()
+                    .fold(0, |acc, (j, codeword)| acc | (codeword.poly >> i & 1) << j)
+                    .into()
+            })
+            .collect())
     }
 
-    #[test]
-    fn test_decode_wrong_length() {
-        const M: u32 = 4;
-        let bch = BCH::<M>::from_max_errors(3).unwrap();
-        let received = PolyGF2::new(0b0);
-        let decoded = bch.decode(received);
-        assert_eq!(decoded, Err("Received message has wrong length"));
+    /// Decodes every interleaved codeword and reports the corrected positions of the
+    /// transmitted stream, where bit `j` of column `i` is position `i * depth + j`.
+    pub fn decode(&self, columns: &[PolyGF2]) -> Result<DecodeResult<Vec<PolyGF2>>, CodingError> {
+        if columns.len() != self.bch.code_length {
+            return Err(CodingError::WrongLength);
+        }
+
+        // De-interleave back into rows and decode each codeword on its own
+        let rows = (0..self.depth)
+            .map(|j| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .fold(0, |acc, (i, column)| acc | (column.poly >> j & 1) << i)
+            })
+            .collect();
+        let decoded = map_blocks(rows, |received| self.bch.correct(PolyGF2::new(received)))
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut messages = Vec::with_capacity(self.depth);
+        let mut error_positions = vec![];
+        for (j, (corrected, positions)) in decoded.into_iter().enumerate() {
+            messages.push(PolyGF2::new(
+                corrected.poly >> self.bch.generator_poly.degree(),
+            ));
+            error_positions.extend(positions.into_iter().map(|i| i * self.depth + j));
+        }
+        error_positions.sort_unstable();
+        Ok(DecodeResult::new(messages, error_positions, 0))
     }
+}
 
-    #[test]
-    #[should_panic]
-    fn test_too_large_distance() {
-        let _bch = BCH::<4>::from_distance(100).unwrap();
+impl<const M: u32> Encoder for BCH<M> {
+    type Message = PolyGF2;
+    type Codeword = PolyGF2;
+
+    fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        BCH::encode(self, message)
+    }
+
+    fn encode_batch(&self, messages: &[PolyGF2]) -> Result<Vec<PolyGF2>, CodingError> {
+        map_blocks(messages.to_vec(), |message| BCH::encode(self, message))
+            .into_iter()
+            .collect()
+    }
+
+    fn parameters(&self) -> (usize, usize, usize) {
+        (self.code_length, self.message_length, self.distance)
+    }
+}
+
+impl<const M: u32> Decoder<BCH<M>> for BCH<M> {
+    fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        BCH::decode(self, received)
+    }
+
+    fn decode_batch(&self, received: &[PolyGF2]) -> Vec<Result<DecodeResult, CodingError>> {
+        map_blocks(received.to_vec(), |received| BCH::decode(self, received))
+    }
+}
+
+impl<const M: u32> Encoder for InterleavedBCH<M> {
+    type Message = Vec<PolyGF2>;
+    type Codeword = Vec<PolyGF2>;
+
+    fn encode(&self, messages: Vec<PolyGF2>) -> Result<Vec<PolyGF2>, CodingError> {
+        InterleavedBCH::encode(self, &messages)
+    }
+
+    fn parameters(&self) -> (usize, usize, usize) {
+        let (n, k, d) = self.bch.parameters();
+        (n * self.depth, k * self.depth, d)
+    }
+}
+
+impl<const M: u32> Decoder<InterleavedBCH<M>> for InterleavedBCH<M> {
+    fn decode(&self, columns: Vec<PolyGF2>) -> Result<DecodeResult<Vec<PolyGF2>>, CodingError> {
+        InterleavedBCH::decode(self, &columns)
+    }
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, count: usize) {
+        for i in 0..count {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            *self.bytes.last_mut().unwrap() |= ((value >> i & 1) as u8) << (self.len % 8);
+            self.len += 1;
+        }
+    }
+}
+
+// Applies `f` to independent codewords, spread over the rayon thread pool when enabled
+#[cfg(feature = "rayon")]
+fn map_blocks<T: Send, U: Send>(blocks: Vec<T>, f: impl Fn(T) -> U + Sync + Send) -> Vec<U> {
+    use rayon::prelude::*;
+    blocks.into_par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn map_blocks<T, U>(blocks: Vec<T>, f: impl Fn(T) -> U) -> Vec<U> {
+    blocks.into_iter().map(f).collect()
+}
+
+// Reads `count` bits starting at bit `offset`, least significant first; bits past the end are 0
+fn read_bits(bytes: &[u8], offset: usize, count: usize) -> u32 {
+    (0..count)
+        .map(|i| offset + i)
+        .filter(|bit| bit / 8 < bytes.len())
+        .fold(0, |acc, bit| {
+            acc | ((bytes[bit / 8] >> (bit % 8) & 1) as u32) << (bit - offset)
+        })
+}
+
+/// A BCH code with some parity positions removed, created by `BCH::puncture`.
+#[derive(Debug, Clone)]
+pub struct PuncturedBCH<const M: u32> {
+    bch: BCH<M>,
+    positions: Vec<usize>,
+}
+
+impl<const M: u32> PuncturedBCH<M> {
+    pub fn code_length(&self) -> usize {
+        self.bch.code_length - self.positions.len()
+    }
+
+    pub fn message_length(&self) -> usize {
+        self.bch.message_length
+    }
+
+    pub fn punctured_positions(&self) -> &[usize] {
+        &self.positions
+    }
+
+    pub fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        let codeword = self.bch.encode_shortened(message, 0)?;
+        let mut punctured = 0;
+        let mut length = 0;
+        for i in 0..self.bch.code_length {
+            if self.positions.binary_search(&i).is_err() {
+                punctured |= (codeword.poly >> i & 1) << length;
+                length += 1;
+            }
+        }
+        Ok(PolyGF2::new(punctured))
+    }
+
+    /// Decodes a punctured word and reports its corrected positions. The punctured
+    /// positions count as erasures.
+    pub fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length() != 0 {
+            return Err(CodingError::WrongLength);
+        }
+
+        // Punctured positions are erasures. Filling them all with zeros and then all with
+        // ones, at least one guess gets half of them right, so one of the two decodings
+        // succeeds whenever 2 * errors + erasures < distance. Keep the one that
+        // had to change the fewest transmitted positions.
+        let mut best: Option<(Vec<usize>, PolyGF2)> = None;
+        for fill in [0, 1] {
+            let expanded = self.expand(received.poly, fill);
+            if let Ok((corrected, error_positions)) = self.bch.correct(PolyGF2::new(expanded)) {
+                // Transmitted positions shift down past the punctured ones before them
+                let errors: Vec<usize> = error_positions
+                    .iter()
+                    .filter_map(|&position| {
+                        let skipped = self.positions.binary_search(&position).err()?;
+                        Some(position - skipped)
+                    })
+                    .collect();
+                if best
+                    .as_ref()
+                    .is_none_or(|(best_errors, _)| errors.len() < best_errors.len())
+                {
+                    best = Some((errors, corrected));
+                }
+            }
+        }
+
+        let (error_positions, corrected) = best.ok_or(CodingError::Uncorrectable)?;
+        Ok(DecodeResult::new(
+            PolyGF2::new(corrected.poly >> self.bch.generator_poly.degree()),
+            error_positions,
+            self.positions.len(),
+        ))
+    }
+
+    fn expand(&self, received: u32, fill: u32) -> u32 {
+        let mut expanded = 0;
+        let mut j = 0;
+        for i in 0..self.bch.code_length {
+            let bit = if self.positions.binary_search(&i).is_ok() {
+                fill
+            } else {
+                j += 1;
+                received >> (j - 1) & 1
+            };
+            expanded |= bit << i;
+        }
+        expanded
+    }
+}
+
+impl<const M: u32> Encoder for PuncturedBCH<M> {
+    type Message = PolyGF2;
+    type Codeword = PolyGF2;
+
+    fn encode(&self, message: PolyGF2) -> Result<PolyGF2, CodingError> {
+        PuncturedBCH::encode(self, message)
+    }
+
+    fn parameters(&self) -> (usize, usize, usize) {
+        (
+            self.code_length(),
+            self.message_length(),
+            self.bch.distance - self.positions.len(),
+        )
+    }
+}
+
+impl<const M: u32> Decoder<PuncturedBCH<M>> for PuncturedBCH<M> {
+    fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        PuncturedBCH::decode(self, received)
+    }
+}
+
+// Polynomials over GF(2^M) are coefficient slices, lowest degree first. Evaluated with Horner's rule
+fn poly_eval<const M: u32>(poly: &[GF2TM<M>], x: GF2TM<M>) -> GF2TM<M> {
+    poly.iter()
+        .rev()
+        .fold(GF2TM::zero(), |acc, coefficient| acc * x + *coefficient)
+}
+
+fn poly_mul<const M: u32>(a: &[GF2TM<M>], b: &[GF2TM<M>]) -> Vec<GF2TM<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![GF2TM::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += *x * *y;
+        }
+    }
+    product
+}
+
+// Λ(x) = x^v L(1 / x) for the monic locator L(x) = Π(x - X_k) of degree v
+fn connection_poly<const M: u32>(error_locator: &[GF2TM<M>]) -> Vec<GF2TM<M>> {
+    let mut connection = error_locator.to_vec();
+    connection.reverse();
+    connection
+}
+
+// Shortest LFSR generating the syndrome sequence S_1, S_2, ..., returned as the connection
+// polynomial Λ(x) = 1 + Λ_1 x + ... + Λ_L x^L whose roots are the inverse error locators
+fn berlekamp_massey<const M: u32>(syndromes: &[GF2TM<M>]) -> Vec<GF2TM<M>> {
+    let mut connection = vec![GF2TM::<M>::one()];
+    let mut previous = vec![GF2TM::<M>::one()];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = GF2TM::<M>::one();
+
+    for n in 0..syndromes.len() {
+        let discrepancy = (1..=length)
+            .filter(|&i| i < connection.len())
+            .fold(syndromes[n], |acc, i| {
+                acc + connection[i] * syndromes[n - i]
+            });
+        if discrepancy.is_zero() {
+            shift += 1;
+            continue;
+        }
+
+        let scale = discrepancy / previous_discrepancy;
+        let mut updated = connection.clone();
+        updated.resize(updated.len().max(previous.len() + shift), GF2TM::zero());
+        for (i, b) in previous.iter().enumerate() {
+            updated[i + shift] -= scale * *b;
+        }
+
+        if 2 * length <= n {
+            previous = connection;
+            length = n + 1 - length;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+        connection = updated;
+    }
+
+    connection.resize(length + 1, GF2TM::zero());
+    connection
+}
+
+/// Solves the key equation σ(x) S(x) = ω(x) mod x^(2t) for the syndrome polynomial
+/// S(x) = S_b + S_(b + 1) x + ... built from the first `2t` syndromes.
+///
+/// Runs the extended Euclidean algorithm on x^(2t) and S(x) until the remainder has degree
+/// below `t`. Returns the error locator σ(x) = Π(1 - X_k x), normalized to σ(0) = 1, and the
+/// error evaluator ω(x), both lowest degree first. `None` if σ(0) = 0, i.e. more than `t`
+/// errors occurred.
+pub fn solve_key_equation<const M: u32>(
+    syndromes: &[GF2TM<M>],
+    t: usize,
+) -> Option<(Vec<GF2TM<M>>, Vec<GF2TM<M>>)> {
+    let mut previous_remainder = vec![GF2TM::zero(); 2 * t];
+    previous_remainder.push(GF2TM::one());
+    let mut remainder = trimmed(syndromes.iter().take(2 * t).copied().collect());
+    let mut previous_locator = vec![];
+    let mut locator = vec![GF2TM::one()];
+
+    while remainder.len() > t {
+        let (quotient, next_remainder) = poly_divmod(&previous_remainder, &remainder);
+        let product = poly_mul(&quotient, &locator);
+        let mut next_locator = previous_locator;
+        next_locator.resize(next_locator.len().max(product.len()), GF2TM::zero());
+        for (coefficient, p) in next_locator.iter_mut().zip(&product) {
+            *coefficient -= *p;
+        }
+
+        previous_remainder = remainder;
+        remainder = next_remainder;
+        previous_locator = locator;
+        locator = trimmed(next_locator);
+    }
+
+    let scale = locator.first().copied().unwrap_or_else(GF2TM::zero);
+    if scale.is_zero() {
+        return None;
+    }
+    let locator: Vec<_> = locator.into_iter().map(|c| c / scale).collect();
+    // The final remainder is σ(x) S(x) mod x^(2t) up to the same scale
+    let evaluator = trimmed(key_equation_evaluator(syndromes, &locator, 2 * t));
+    Some((locator, evaluator))
+}
+
+// ω(x) = σ(x) S(x) mod x^len for the connection polynomial σ(x), lowest degree first; the
+// error evaluator of both `solve_key_equation` and `BCH::error_evaluator`
+fn key_equation_evaluator<const M: u32>(
+    syndromes: &[GF2TM<M>],
+    connection: &[GF2TM<M>],
+    len: usize,
+) -> Vec<GF2TM<M>> {
+    let syndromes = &syndromes[..len.min(syndromes.len())];
+    let mut evaluator = poly_mul(syndromes, connection);
+    evaluator.truncate(len);
+    evaluator
+}
+
+// Quotient and remainder of polynomials over GF(2^M), lowest degree first; `b` must be trimmed
+fn poly_divmod<const M: u32>(a: &[GF2TM<M>], b: &[GF2TM<M>]) -> (Vec<GF2TM<M>>, Vec<GF2TM<M>>) {
+    let mut remainder = trimmed(a.to_vec());
+    if remainder.len() < b.len() {
+        return (vec![], remainder);
+    }
+    let lead = *b.last().unwrap();
+    let mut quotient = vec![GF2TM::zero(); remainder.len() - b.len() + 1];
+    for shift in (0..quotient.len()).rev() {
+        let factor = remainder[shift + b.len() - 1] / lead;
+        quotient[shift] = factor;
+        for (i, coefficient) in b.iter().enumerate() {
+            remainder[shift + i] -= factor * *coefficient;
+        }
+    }
+    remainder.truncate(b.len() - 1);
+    (quotient, trimmed(remainder))
+}
+
+fn trimmed<const M: u32>(mut poly: Vec<GF2TM<M>>) -> Vec<GF2TM<M>> {
+    while poly.last().is_some_and(|c| c.is_zero()) {
+        poly.pop();
+    }
+    poly
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use galois::PolyGF2;
+
+    #[test]
+    fn test_encode() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let message = PolyGF2::new(0b11011);
+        let encoded = bch.encode(message);
+        assert_eq!(encoded, Ok(PolyGF2::new(0b110111000010100)));
+    }
+
+    #[test]
+    fn test_decode_2_err() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let message = PolyGF2::new(0b11011);
+        let encoded = bch.encode(message).unwrap();
+        let err = 0b10000000100000;
+        let received = PolyGF2::new(encoded.poly ^ err);
+        let result = bch.decode(received).unwrap();
+        assert_eq!(*result.message(), message);
+        assert_eq!(result.num_errors, 2);
+        assert_eq!(result.num_erasures, 0);
+        assert_eq!(result.error_positions, vec![5, 13]);
+        let mask = result.error_positions.iter().fold(0, |acc, e| acc | 1 << e);
+        assert_eq!(mask, err);
+    }
+
+    #[test]
+    fn test_decode_positions() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let message = PolyGF2::new(0b10110);
+
+        // Positions 2 and 9 of the full codeword are 1 and 7 once 0 and 4 are punctured
+        let punctured = bch.puncture(&[0, 4]).unwrap();
+        let received = inject_errors(punctured.encode(message).unwrap(), &[1, 7]);
+        let result = punctured.decode(received).unwrap();
+        assert_eq!(*result.message(), message);
+        assert_eq!(result.error_positions, vec![1, 7]);
+        assert_eq!((result.num_errors, result.num_erasures), (2, 2));
+
+        let alpha = GF2TM::<M>::primitive_element();
+        let symbols: Vec<_> = (0..5).map(|i| alpha.pow(i)).collect();
+        let mut received = bch.encode_symbols(&symbols).unwrap();
+        received[3] += alpha;
+        received[12] += alpha.pow(6);
+        let result = bch.decode_symbols(&received).unwrap();
+        assert_eq!(*result.message(), symbols);
+        assert_eq!(result.error_positions, vec![3, 12]);
+
+        let hamming = HammingCode::<3>::new().unwrap();
+        let received = inject_errors(hamming.encode(PolyGF2::new(0b1011)).unwrap(), &[4]);
+        let result = hamming.decode(received).unwrap();
+        assert_eq!(*result.message(), PolyGF2::new(0b1011));
+        assert_eq!(result.error_positions, vec![4]);
+
+        let cyclic = CyclicCode::new(bch.generator_poly(), 15).unwrap();
+        let received = inject_errors(cyclic.encode(message).unwrap(), &[0, 6, 14]);
+        let result = cyclic.decode(received).unwrap();
+        assert_eq!(*result.message(), message);
+        assert_eq!(result.error_positions, vec![0, 6, 14]);
+        assert_eq!(result.num_errors, 3);
+    }
+
+    #[test]
+    fn test_decode_3_err() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let message = PolyGF2::new(0b11011);
+        let encoded = bch.encode(message).unwrap();
+        let received = inject_errors(encoded, &[5, 10, 13]);
+        let decoded = bch.decode(received).unwrap();
+        assert_eq!(*decoded.message(), message);
+        assert_eq!(decoded.error_positions, vec![5, 10, 13]);
+    }
+
+    #[test]
+    fn test_decode_uncorrectable() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let message = PolyGF2::new(0b11011);
+        let encoded = bch.encode(message).unwrap();
+        let err = 0b10010000100011;
+        let received = PolyGF2::new(encoded.poly ^ err);
+        assert_eq!(bch.decode(received), Err(CodingError::Uncorrectable));
+
+        // Four errors are either detected or land within distance 3 of another codeword
+        let mut detected = 0;
+        for a in 0..14 {
+            for b in a + 1..14 {
+                for c in b + 1..14 {
+                    for d in c + 1..14 {
+                        let err = 1 << a | 1 << b | 1 << c | 1 << d;
+                        let received = PolyGF2::new(encoded.poly ^ err);
+                        match bch.decode(received) {
+                            Ok(result) => {
+                                assert_ne!(*result.message(), message);
+                                assert!(result.num_errors <= 3);
+                            }
+                            Err(e) => {
+                                assert_eq!(e, CodingError::Uncorrectable);
+                                detected += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        assert!(detected > 0);
+    }
+
+    #[test]
+    fn test_shortened() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let shorten = 2;
+        let message = PolyGF2::new(0b101);
+        let encoded = bch.encode_shortened(message, shorten).unwrap();
+        assert!(encoded.degree() < 13);
+        assert!(bch.is_codeword(encoded));
+
+        let received = PolyGF2::new(encoded.poly ^ 1 << 11);
+        assert_eq!(
+            bch.decode_shortened(received, shorten).unwrap().message(),
+            &message
+        );
+        assert_eq!(
+            bch.decode_shortened(PolyGF2::new(0), shorten)
+                .unwrap()
+                .message(),
+            &PolyGF2::new(0)
+        );
+
+        assert_eq!(
+            bch.encode_shortened(PolyGF2::new(0b1000), shorten),
+            Err(CodingError::MessageTooLong)
+        );
+        assert_eq!(
+            bch.decode_shortened(PolyGF2::new(1 << 13), shorten),
+            Err(CodingError::WrongLength)
+        );
+    }
+
+    #[test]
+    fn test_punctured() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let punctured = bch.puncture(&[1, 6]).unwrap();
+        assert_eq!(punctured.code_length(), 13);
+        assert_eq!(punctured.message_length(), 5);
+
+        let message = PolyGF2::new(0b10011);
+        let encoded = punctured.encode(message).unwrap();
+        assert!(encoded.poly >> 13 == 0);
+        assert_eq!(punctured.decode(encoded).unwrap().message(), &message);
+        for i in 0..13 {
+            let received = PolyGF2::new(encoded.poly ^ 1 << i);
+            assert_eq!(punctured.decode(received).unwrap().message(), &message);
+        }
+
+        assert!(bch.puncture(&[12]).is_err());
+        assert!(bch.puncture(&[0, 1, 2, 3, 4, 5]).is_err());
+    }
+
+    #[test]
+    fn test_from_params() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_params(0, 7).unwrap();
+        assert_eq!(bch.first_root(), 0);
+        // The narrow-sense generator for distance 7 times the minimal polynomial of 1
+        let narrow = BCH::<M>::from_distance(7).unwrap();
+        assert_eq!(
+            bch.generator_poly(),
+            narrow.generator_poly() * PolyGF2::new(0b11)
+        );
+        assert_eq!(bch.message_length(), 4);
+
+        let message = PolyGF2::new(0b1011);
+        let encoded = bch.encode(message).unwrap();
+        assert!(bch.is_codeword(encoded));
+        let parity_check = bch.parity_check_matrix();
+        let codeword: Vec<_> = (0..15)
+            .map(|j| GF2TM::from(encoded.poly >> j & 1))
+            .collect();
+        assert!(parity_check.vec_mul(&codeword).iter().all(|s| s.is_zero()));
+
+        let received = PolyGF2::new(encoded.poly ^ 0b100100000001);
+        assert_eq!(bch.decode(received).unwrap().message(), &message);
+
+        let alpha = GF2TM::<M>::primitive_element();
+        let symbols = vec![alpha, alpha.pow(7), GF2TM::zero(), alpha.pow(12)];
+        let mut received = bch.encode_symbols(&symbols).unwrap();
+        received[0] += alpha.pow(3);
+        received[8] += alpha.pow(9);
+        received[14] += alpha;
+        assert_eq!(bch.decode_symbols(&received).unwrap().message(), &symbols);
+
+        assert!(BCH::<M>::from_params(0, 1).is_err());
+        assert!(BCH::<M>::from_params(2, 16).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let data = b"Hello, BCH!";
+        let mut encoded = bch.encode_bytes(data).unwrap();
+        let codewords = ((4 + data.len()) * 8).div_ceil(5);
+        assert_eq!(encoded.len(), (codewords * 15).div_ceil(8));
+        assert_eq!(bch.decode_bytes(&encoded).unwrap(), data);
+
+        encoded[0] ^= 0b1000;
+        encoded[2] ^= 0b1;
+        encoded[13] ^= 0b10100000;
+        assert_eq!(bch.decode_bytes(&encoded).unwrap(), data);
+
+        assert_eq!(
+            bch.decode_bytes(&bch.encode_bytes(&[]).unwrap()).unwrap(),
+            vec![]
+        );
+        assert!(bch.decode_bytes(&[]).is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_bytes_parallel_matches_sequential() {
+        const M: u32 = 5;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let data: Vec<u8> = (0..=255).collect();
+        let encoded = bch.encode_bytes(&data).unwrap();
+
+        let mut stream = (data.len() as u32).to_le_bytes().to_vec();
+        stream.extend_from_slice(&data);
+        let mut sequential = BitWriter::default();
+        for offset in (0..stream.len() * 8).step_by(bch.message_length()) {
+            let message = read_bits(&stream, offset, bch.message_length());
+            let codeword = bch.encode_shortened(PolyGF2::new(message), 0).unwrap();
+            sequential.write(codeword.poly, bch.code_length());
+        }
+        assert_eq!(encoded, sequential.bytes);
+
+        let mut corrupted = encoded.clone();
+        for i in (0..corrupted.len()).step_by(7) {
+            corrupted[i] ^= 0b100;
+        }
+        assert_eq!(bch.decode_bytes(&corrupted).unwrap(), data);
+    }
+
+    #[test]
+    fn test_interleaved() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let interleaved = InterleavedBCH::new(bch, 4).unwrap();
+        let messages: Vec<_> = [0b11011, 0b00001, 0b10000, 0b01110]
+            .into_iter()
+            .map(PolyGF2::new)
+            .collect();
+        let mut columns = interleaved.encode(&messages).unwrap();
+        assert_eq!(columns.len(), 15);
+
+        // A burst of 12 consecutive channel bits is only 3 errors per codeword
+        for bit in 5..17 {
+            columns[bit / 4].poly ^= 1 << (bit % 4);
+        }
+        let result = interleaved.decode(&columns).unwrap();
+        assert_eq!(*result.message(), messages);
+        assert_eq!(result.error_positions, (5..17).collect::<Vec<_>>());
+        assert_eq!(result.num_errors, 12);
+
+        // Without interleaving the same burst is far beyond t = 3
+        let codeword = bch.encode(messages[0]).unwrap();
+        let received = PolyGF2::new(codeword.poly ^ 0b111111111111 << 2);
+        assert!(bch
+            .decode(received)
+            .is_ok_and(|result| result.message != messages[0]));
+
+        assert!(InterleavedBCH::new(bch, 0).is_err());
+        assert!(interleaved.encode(&messages[..3]).is_err());
+    }
+
+    fn round_trip<C: LinearCode>(code: &C, message: C::Message)
+    where
+        C::Message: Clone + PartialEq + std::fmt::Debug,
+    {
+        let (n, k, d) = code.parameters();
+        assert!(k < n && d > 1);
+        let encoded = code.encode(message.clone()).unwrap();
+        assert_eq!(code.decode(encoded).unwrap().message(), &message);
+    }
+
+    #[test]
+    fn test_linear_code() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        assert_eq!(Encoder::parameters(&bch), (15, 5, 7));
+        for message in 0..1 << 5 {
+            round_trip(&bch, PolyGF2::new(message));
+        }
+        assert_eq!(
+            Encoder::encode(&bch, PolyGF2::new(0b11011)),
+            bch.encode(PolyGF2::new(0b11011))
+        );
+
+        let punctured = bch.puncture(&[0, 3]).unwrap();
+        assert_eq!(punctured.parameters(), (13, 5, 5));
+        round_trip(&punctured, PolyGF2::new(0b10101));
+
+        let interleaved = InterleavedBCH::new(bch, 3).unwrap();
+        assert_eq!(interleaved.parameters(), (45, 15, 7));
+        round_trip(
+            &interleaved,
+            vec![PolyGF2::new(1), PolyGF2::new(0), PolyGF2::new(31)],
+        );
+    }
+
+    fn decode_all<C: Encoder, D: Decoder<C>>(
+        decoder: &D,
+        received: &[C::Codeword],
+    ) -> Vec<Result<DecodeResult<C::Message>, CodingError>>
+    where
+        C::Codeword: Clone,
+    {
+        decoder.decode_blocks(received.iter().cloned()).collect()
+    }
+
+    #[test]
+    fn test_swap_decoders() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let messages = (0..32u32).map(PolyGF2::new);
+        // One to three errors, and four in every fourth word
+        let received: Vec<_> = bch
+            .encode_blocks(messages.clone())
+            .enumerate()
+            .map(|(i, codeword)| {
+                let error = (0..=i % 4).fold(0, |acc, j| acc | 1 << ((i + 4 * j) % 15));
+                PolyGF2::new(codeword.unwrap().poly ^ error)
+            })
+            .collect();
+
+        let expected = decode_all::<BCH<M>, _>(&bch, &received);
+        for (i, (decoded, message)) in expected.iter().zip(messages).enumerate() {
+            let decoded = decoded.as_ref().map(|result| result.message);
+            assert_eq!(decoded == Ok(message), i % 4 != 3, "block {i}");
+        }
+        for algorithm in [LocatorAlgorithm::LinearSystem, LocatorAlgorithm::Euclidean] {
+            let decoder = bch.with_locator_algorithm(algorithm);
+            assert_eq!(decode_all::<BCH<M>, _>(&decoder, &received), expected);
+        }
+
+        // The cyclic code with the same generator encodes identically
+        let cyclic = CyclicCode::new(bch.generator_poly(), 15).unwrap();
+        let meggitt = MeggittDecoder::new(cyclic.clone());
+        let within_radius: Vec<_> = received.iter().step_by(4).copied().collect();
+        assert_eq!(
+            decode_all::<CyclicCode, _>(&meggitt, &received),
+            decode_all::<CyclicCode, _>(&cyclic, &received)
+        );
+        assert_eq!(
+            decode_all::<CyclicCode, _>(&meggitt, &within_radius),
+            decode_all::<BCH<M>, _>(&bch, &within_radius)
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let messages = (0..100u32).map(|i| PolyGF2::new(i * 7 % 32));
+
+        // Flip up to three bits of every codeword, and four in block 50
+        let received = bch
+            .encode_blocks(messages.clone())
+            .enumerate()
+            .map(|(i, codeword)| {
+                let error = if i == 50 {
+                    0b1111
+                } else {
+                    (1 << (i % 15)) | (i as u32 & 0b11) << 13
+                };
+                PolyGF2::new(codeword.unwrap().poly ^ error)
+            });
+        let decoded: Vec<_> = bch.decode_blocks(received).collect();
+        assert_eq!(decoded.len(), 100);
+        for (i, (decoded, message)) in decoded.into_iter().zip(messages).enumerate() {
+            let decoded = decoded.map(|result| result.message);
+            if i == 50 {
+                assert_ne!(decoded, Ok(message));
+            } else {
+                assert_eq!(decoded, Ok(message), "block {i}");
+            }
+        }
+
+        let mut encoded = bch.encode_blocks([PolyGF2::new(1), PolyGF2::new(1 << 5)]);
+        assert!(encoded.next().unwrap().is_ok());
+        assert_eq!(encoded.next(), Some(Err(CodingError::MessageTooLong)));
+        assert_eq!(encoded.next(), None);
+    }
+
+    #[test]
+    fn test_batch() {
+        const M: u32 = 5;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let messages: Vec<_> = (0..50u32)
+            .map(|i| PolyGF2::new(i * 40503 % (1 << 16)))
+            .collect();
+        let encoded = bch.encode_batch(&messages).unwrap();
+        let expected: Vec<_> = messages
+            .iter()
+            .map(|&message| Encoder::encode(&bch, message).unwrap())
+            .collect();
+        assert_eq!(encoded, expected);
+
+        let received: Vec<_> = encoded
+            .iter()
+            .enumerate()
+            .map(|(i, &codeword)| inject_errors(codeword, &[i % 31, (7 * i + 3) % 31]))
+            .collect();
+        let decoded = bch.decode_batch(&received);
+        let expected: Vec<_> = received
+            .iter()
+            .map(|&received| Decoder::<BCH<M>>::decode(&bch, received))
+            .collect();
+        assert_eq!(decoded, expected);
+        assert_eq!(
+            decoded
+                .into_iter()
+                .map(|result| result.map(|result| result.message))
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(messages)
+        );
+        assert_eq!(
+            bch.encode_batch(&[PolyGF2::new(1), PolyGF2::new(1 << 16)]),
+            Err(CodingError::MessageTooLong)
+        );
+
+        // The default implementations
+        let hamming = HammingCode::<3>::new().unwrap();
+        let messages: Vec<_> = (0..16).map(PolyGF2::new).collect();
+        let encoded = hamming.encode_batch(&messages).unwrap();
+        let received: Vec<_> = encoded
+            .iter()
+            .enumerate()
+            .map(|(i, &codeword)| inject_errors(codeword, &[i % 7]))
+            .collect();
+        let decoded: Vec<_> = hamming
+            .decode_batch(&received)
+            .into_iter()
+            .map(|result| result.unwrap().message)
+            .collect();
+        assert_eq!(decoded, messages);
+    }
+
+    #[test]
+    fn test_decode_wrong_length() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let received = PolyGF2::new(1 << 15 | 0b1);
+        let decoded = bch.decode(received);
+        assert_eq!(decoded, Err(CodingError::WrongLength));
+        // Codewords with leading zeros are still full-length words
+        assert_eq!(
+            bch.decode(PolyGF2::new(0)).unwrap().message(),
+            &PolyGF2::new(0)
+        );
+    }
+
+    #[test]
+    fn test_parameters() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        assert_eq!(bch.code_length(), 15);
+        assert_eq!(bch.message_length(), 5);
+        assert_eq!(bch.distance(), 7);
+        // (x^4 + x + 1)(x^4 + x^3 + x^2 + x + 1)(x^2 + x + 1)
+        assert_eq!(bch.generator_poly(), PolyGF2::new(0b10100110111));
+    }
+
+    #[test]
+    fn test_weight_distribution() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let distribution = bch.weight_distribution().unwrap();
+        assert_eq!(distribution.len(), 16);
+        assert_eq!(distribution.iter().sum::<usize>(), 32);
+
+        let min_weight = (1..16).find(|&w| distribution[w] > 0);
+        assert_eq!(min_weight, Some(bch.distance()));
+        // The (15, 5, 7) BCH code has weight enumerator 1 + 15z^7 + 15z^8 + z^15
+        let mut expected = vec![0; 16];
+        expected[0] = 1;
+        expected[7] = 15;
+        expected[8] = 15;
+        expected[15] = 1;
+        assert_eq!(distribution, expected);
+
+        let large = BCH::<5>::from_distance(3).unwrap();
+        assert!(large.weight_distribution().is_err());
+    }
+
+    #[test]
+    fn test_minimum_distance_bruteforce() {
+        let hamming = BCH::<3>::from_distance(3).unwrap();
+        assert_eq!(hamming.minimum_distance_bruteforce(), Ok(3));
+        for distance in [3, 5, 7] {
+            let bch = BCH::<4>::from_distance(distance).unwrap();
+            assert_eq!(bch.minimum_distance_bruteforce(), Ok(distance));
+        }
+        // The design distance is only a lower bound: for b = 0 the extra root at 1
+        // makes every codeword weight even
+        let bch = BCH::<4>::from_params(0, 7).unwrap();
+        assert_eq!(bch.minimum_distance_bruteforce(), Ok(8));
+
+        let large = BCH::<5>::from_distance(3).unwrap();
+        assert!(large.minimum_distance_bruteforce().is_err());
+    }
+
+    #[test]
+    fn test_generator_matrix() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let generator = bch.generator_matrix();
+        assert_eq!(generator.rows(), 5);
+        assert_eq!(generator.cols(), 15);
+
+        let shifted = bch.generator_poly * PolyGF2::new(1 << 2);
+        for j in 0..15 {
+            assert_eq!(generator[[2, j]], GF2TM::from(shifted.poly >> j & 1));
+        }
+        assert_eq!(generator.rank(), 5);
+
+        // Codewords produced by `encode` lie in the row space of the generator matrix
+        let encoded = bch.encode(PolyGF2::new(0b11011)).unwrap();
+        let codeword: Vec<_> = (0..15)
+            .map(|j| GF2TM::from(encoded.poly >> j & 1))
+            .collect();
+        let extended = generator.vstack(&Matrix::from_rows(vec![codeword]));
+        assert_eq!(extended.rank(), 5);
+    }
+
+    #[test]
+    fn test_parity_check_matrix() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let parity_check = bch.parity_check_matrix();
+        assert_eq!(parity_check.rows(), 6);
+        assert_eq!(parity_check.cols(), 15);
+
+        for message in 1..1 << bch.max_message_length() {
+            let encoded = bch.encode(PolyGF2::new(message)).unwrap();
+            let codeword: Vec<_> = (0..15)
+                .map(|j| GF2TM::from(encoded.poly >> j & 1))
+                .collect();
+            assert!(parity_check.vec_mul(&codeword).iter().all(|s| s.is_zero()));
+        }
+
+        let corrupted: Vec<_> = (0..15).map(|j| GF2TM::from((j == 3) as u32)).collect();
+        assert!(!parity_check.vec_mul(&corrupted).iter().all(|s| s.is_zero()));
+    }
+
+    #[test]
+    fn test_systematic_generator_matrix() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let generator = bch.systematic_generator_matrix();
+        assert_eq!(generator.rows(), 5);
+        assert_eq!(generator.submatrix(0..5, 0..5), Matrix::identity(5));
+        let product = generator.matmul(&bch.parity_check_matrix().transpose());
+        assert_eq!(product, Matrix::zero(5, 6));
+        // Same code as the polynomial generator matrix
+        assert_eq!(generator.vstack(&bch.generator_matrix()).rank(), 5);
+
+        // Encoding by matrix product puts the message in front and yields a codeword
+        let message: Vec<_> = [1u32, 1, 0, 1, 1].map(GF2TM::from).into();
+        let codeword = generator.transpose().vec_mul(&message);
+        assert_eq!(codeword[..5], message[..]);
+        let poly = codeword
+            .iter()
+            .rev()
+            .fold(0, |acc, bit| acc << 1 | bit.value().poly);
+        assert!(bch.is_codeword(PolyGF2::new(poly)));
+    }
+
+    #[test]
+    fn test_dual() {
+        fn check<const M: u32>(bch: BCH<M>) {
+            let n = bch.code_length();
+            let dual = bch.dual();
+            assert_eq!(bch.message_length() + dual.message_length(), n);
+            assert_eq!(dual.code_length(), n);
+
+            // The dual is the null space of the generator matrix
+            let generator = bch.generator_matrix();
+            assert_eq!(generator.null_space().len(), dual.message_length());
+            for i in 0..dual.message_length() {
+                let codeword = dual.encode(PolyGF2::new(1 << i)).unwrap();
+                for row in 0..generator.rows() {
+                    let product = (0..n).fold(GF2TM::<M>::zero(), |acc, j| {
+                        acc + generator[[row, j]] * GF2TM::from(codeword.poly >> j & 1)
+                    });
+                    assert!(product.is_zero());
+                }
+            }
+
+            // (C^⊥)^⊥ = C, generated by the reciprocal of the check polynomial of C^⊥
+            let check = PolyGF2::new(1 << n | 1) / dual.generator_poly();
+            let k = dual.message_length();
+            let reciprocal = (0..=k).fold(0, |acc, i| acc | (check.poly >> i & 1) << (k - i));
+            assert_eq!(PolyGF2::new(reciprocal), bch.generator_poly());
+        }
+
+        for distance in [3, 5, 7] {
+            check(BCH::<4>::from_distance(distance).unwrap());
+        }
+        check(BCH::<5>::from_distance(5).unwrap());
+        // A [31, 5] dual correcting 7 errors, cheap as long as it is not decoded
+        check(BCH::<5>::from_distance(3).unwrap());
+
+        // The dual of the (7, 4) Hamming code is the (7, 3) simplex code with distance 4
+        let simplex = BCH::<3>::from_distance(3).unwrap().dual();
+        assert_eq!(simplex.message_length(), 3);
+        assert!((1..8)
+            .map(|m| simplex.encode(PolyGF2::new(m)).unwrap().poly.count_ones())
+            .all(|weight| weight == 4));
+    }
+
+    #[test]
+    fn test_syndromes() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let encoded = bch.encode(PolyGF2::new(0b11011)).unwrap();
+        let syndromes = bch.syndromes(encoded);
+        assert_eq!(syndromes.len(), 6);
+        assert!(syndromes.iter().all(|s| s.is_zero()));
+
+        let received = PolyGF2::new(encoded.poly ^ 0b100000000);
+        assert!(!bch.syndromes(received).iter().all(|s| s.is_zero()));
+        // A single error at position i has syndromes S_j = α^(i * j)
+        assert_eq!(bch.syndromes(received)[1], bch.primitive_element.pow(16));
+    }
+
+    #[test]
+    fn test_syndromes_match_evaluation() {
+        const M: u32 = 4;
+        let alpha = GF2TM::<M>::primitive_element();
+        let received: Vec<_> = (0..15).map(|i| alpha.pow(i * i % 7)).collect();
+        for first_root in 0..3 {
+            let bch = BCH::<M>::from_params(first_root, 7).unwrap();
+            let expected: Vec<_> = (first_root..first_root + 6)
+                .map(|i| poly_eval(&received, alpha.pow(i as u32)))
+                .collect();
+            assert_eq!(bch.symbol_syndromes(&received), expected);
+        }
+    }
+
+    #[test]
+    fn test_is_codeword() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let encoded = bch.encode(PolyGF2::new(0b11011)).unwrap();
+        assert!(bch.is_codeword(encoded));
+        assert!(!bch.detect_errors(encoded));
+        assert!(bch.is_codeword(PolyGF2::new(0)));
+
+        let received = PolyGF2::new(encoded.poly ^ 0b1000010);
+        assert!(!bch.is_codeword(received));
+        assert!(bch.detect_errors(received));
+        assert!(!bch.is_codeword(PolyGF2::new(encoded.poly | 1 << 15)));
+    }
+
+    #[test]
+    fn test_message_and_parity_parts() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(5).unwrap();
+        let parity_length = bch.code_length() - bch.message_length();
+        for message in [0, 1, 0b1011001, (1 << bch.message_length()) - 1] {
+            let message = PolyGF2::new(message);
+            let encoded = bch.encode_shortened(message, 0).unwrap();
+            assert_eq!(bch.encode(message), Ok(encoded));
+            assert_eq!(Encoder::encode(&bch, message), Ok(encoded));
+            assert_eq!(bch.message_part(encoded), message);
+            let shifted = PolyGF2::new(message.poly << parity_length);
+            assert_eq!(bch.parity_part(encoded), shifted % bch.generator_poly());
+            assert_eq!(
+                PolyGF2::new(bch.message_part(encoded).poly << parity_length)
+                    + bch.parity_part(encoded),
+                encoded
+            );
+        }
+    }
+
+    #[test]
+    fn test_berlekamp_massey_matches_linear_system() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let linear = bch.with_locator_algorithm(LocatorAlgorithm::LinearSystem);
+        let codeword = bch.encode(PolyGF2::new(0b10110)).unwrap().poly;
+
+        let mut patterns = vec![];
+        for a in 0..15 {
+            patterns.push(1 << a);
+            for b in a + 1..15 {
+                patterns.push(1 << a | 1 << b);
+                for c in (b + 1..15).step_by(4) {
+                    patterns.push(1 << a | 1 << b | 1 << c);
+                }
+            }
+        }
+
+        for err in patterns {
+            let syndromes = bch.syndromes(PolyGF2::new(codeword ^ err));
+            assert_eq!(
+                bch.error_locator(&syndromes),
+                linear.error_locator(&syndromes),
+                "error pattern {err:b}"
+            );
+            let received = PolyGF2::new(codeword ^ err);
+            assert_eq!(bch.decode(received), linear.decode(received));
+        }
+    }
+
+    #[test]
+    fn test_key_equation() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let linear = bch.with_locator_algorithm(LocatorAlgorithm::LinearSystem);
+        let euclidean = bch.with_locator_algorithm(LocatorAlgorithm::Euclidean);
+        let alpha = GF2TM::<M>::primitive_element();
+        let message: Vec<_> = (0..5).map(|i| alpha.pow(2 * i + 1)).collect();
+        let encoded = bch.encode_symbols(&message).unwrap();
+
+        let patterns: [&[(usize, u32)]; 4] = [
+            &[],
+            &[(4, 0)],
+            &[(0, 3), (14, 9)],
+            &[(2, 5), (6, 0), (11, 12)],
+        ];
+        for errors in patterns {
+            let mut received = encoded.clone();
+            for &(position, exponent) in errors {
+                received[position] += alpha.pow(exponent);
+            }
+            let syndromes = bch.symbol_syndromes(&received);
+            let (locator, evaluator) = solve_key_equation(&syndromes, 3).unwrap();
+            assert_eq!(locator.len(), errors.len() + 1);
+
+            if errors.is_empty() {
+                assert_eq!((locator, evaluator), (vec![GF2TM::one()], vec![]));
+                continue;
+            }
+            let expected = linear.error_locator(&syndromes).unwrap();
+            assert_eq!(locator, connection_poly(&expected), "{errors:?}");
+            let expected = bch.error_evaluator(&syndromes, &expected);
+            assert_eq!(evaluator, trimmed(expected));
+            assert_eq!(
+                euclidean.decode_symbols(&received).unwrap().message(),
+                &message.clone()
+            );
+        }
+    }
+
+    #[test]
+    fn test_locator_and_evaluator() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let alpha = GF2TM::<M>::primitive_element();
+        let message: Vec<_> = (0..5).map(|i| alpha.pow(4 * i + 2)).collect();
+        let encoded = bch.encode_symbols(&message).unwrap();
+        let mut received = encoded.clone();
+        for (position, exponent) in [(3, 7), (8, 0), (13, 10)] {
+            received[position] += alpha.pow(exponent);
+        }
+
+        let (locator, evaluator) = bch.locator_and_evaluator(&received).unwrap();
+        assert_eq!(locator.len() - 1, 3);
+        assert!(evaluator.len() - 1 < locator.len() - 1);
+        assert_eq!(locator[0], GF2TM::one());
+
+        // Forney's formula by hand from the pair, with the monic reversal as error locator
+        let error_locator = connection_poly(&locator);
+        let positions = bch.chien_search(&error_locator);
+        assert_eq!(positions, vec![3, 8, 13]);
+        let magnitudes = bch.error_magnitudes(&error_locator, &evaluator, &positions);
+        for (position, magnitude) in positions.into_iter().zip(magnitudes) {
+            assert_eq!(magnitude, received[position] - encoded[position]);
+        }
+
+        assert_eq!(
+            bch.locator_and_evaluator(&encoded),
+            Ok((vec![GF2TM::one()], vec![]))
+        );
+        assert_eq!(
+            bch.locator_and_evaluator(&received[1..]),
+            Err(CodingError::WrongLength)
+        );
+    }
+
+    #[test]
+    fn test_decode_euclidean() {
+        const M: u32 = 5;
+        let bch = BCH::<M>::from_max_errors(4)
+            .unwrap()
+            .with_locator_algorithm(LocatorAlgorithm::Euclidean);
+        let message = PolyGF2::new(0b1011001110);
+        let encoded = bch.encode_shortened(message, 0).unwrap();
+        let received = inject_errors(encoded, &[1, 8, 20, 29]);
+        assert_eq!(
+            bch.decode_shortened(received, 0).unwrap().message(),
+            &message
+        );
+    }
+
+    #[test]
+    fn test_decode_linear_system() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3)
+            .unwrap()
+            .with_locator_algorithm(LocatorAlgorithm::LinearSystem);
+        let message = PolyGF2::new(0b11011);
+        let encoded = bch.encode(message).unwrap();
+        let err = 0b10010000100000;
+        let received = PolyGF2::new(encoded.poly ^ err);
+        assert_eq!(bch.decode(received).unwrap().message(), &message);
+    }
+
+    #[test]
+    fn test_decode_symbols() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let alpha = GF2TM::<M>::primitive_element();
+        let message: Vec<_> = (0..bch.max_message_length())
+            .map(|i| alpha.pow(3 * i as u32 + 1))
+            .collect();
+        let encoded = bch.encode_symbols(&message).unwrap();
+        assert!(bch.symbol_syndromes(&encoded).iter().all(|s| s.is_zero()));
+
+        let mut received = encoded.clone();
+        received[2] += alpha.pow(5);
+        received[9] += alpha.pow(11);
+        received[13] += GF2TM::one();
+        assert_eq!(
+            bch.decode_symbols(&received).unwrap().message(),
+            &message.clone()
+        );
+
+        let linear = bch.with_locator_algorithm(LocatorAlgorithm::LinearSystem);
+        assert_eq!(
+            linear.decode_symbols(&received).unwrap().message(),
+            &message
+        );
+    }
+
+    #[test]
+    fn test_error_magnitudes() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let alpha = GF2TM::<M>::primitive_element();
+        let message: Vec<_> = (0..5).map(|i| alpha.pow(i * i)).collect();
+        let encoded = bch.encode_symbols(&message).unwrap();
+
+        let errors = [(1, alpha.pow(4)), (7, alpha.pow(13)), (12, alpha.pow(8))];
+        let mut received = encoded.clone();
+        for (position, value) in errors {
+            received[position] += value;
+        }
+
+        let syndromes = bch.symbol_syndromes(&received);
+        let error_locator = bch.error_locator(&syndromes).unwrap();
+        let positions = bch.chien_search(&error_locator);
+        assert_eq!(positions, vec![1, 7, 12]);
+        let error_evaluator = bch.error_evaluator(&syndromes, &error_locator);
+        let magnitudes = bch.error_magnitudes(&error_locator, &error_evaluator, &positions);
+        for (position, magnitude) in positions.into_iter().zip(magnitudes) {
+            assert_eq!(magnitude, received[position] - encoded[position]);
+        }
+    }
+
+    #[test]
+    fn test_chien_search_matches_evaluation() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_max_errors(3).unwrap();
+        let alpha = GF2TM::<M>::primitive_element();
+        let naive = |locator: &[GF2TM<M>]| -> Vec<usize> {
+            (0..15)
+                .filter(|&i| poly_eval(locator, alpha.pow(i as u32)).is_zero())
+                .collect()
+        };
+        for a in 0..15u32 {
+            for b in 0..15u32 {
+                let locator = [alpha.pow(a), alpha.pow(b), alpha.pow(a * b), GF2TM::one()];
+                assert_eq!(bch.chien_search(&locator), naive(&locator));
+            }
+        }
+        let locator = poly_mul(
+            &poly_mul(&[alpha.pow(3), GF2TM::one()], &[alpha.pow(9), GF2TM::one()]),
+            &[alpha, GF2TM::one()],
+        );
+        assert_eq!(bch.chien_search(&locator), vec![1, 3, 9]);
+    }
+
+    #[test]
+    fn test_encode_symbols_matches_binary() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(7).unwrap();
+        let message = 0b11011;
+        let symbols: Vec<_> = (0..bch.max_message_length())
+            .map(|i| GF2TM::<M>::from(message >> i & 1))
+            .collect();
+        let encoded = bch.encode(PolyGF2::new(message)).unwrap();
+        let expected: Vec<_> = (0..15)
+            .map(|i| GF2TM::<M>::from(encoded.poly >> i & 1))
+            .collect();
+        assert_eq!(bch.encode_symbols(&symbols), Ok(expected));
+    }
+
+    #[test]
+    fn test_too_large_distance() {
+        assert_eq!(
+            BCH::<4>::from_distance(100).unwrap_err(),
+            CodingError::InvalidParameters("Max allowed distance is 2^M - 1")
+        );
+        assert!(BCH::<4>::from_distance(16).is_err());
+        assert!(BCH::<4>::from_distance(15).is_ok());
+        assert!(BCH::<3>::from_max_errors(4).is_err());
+    }
+
+    #[test]
+    fn test_too_large_field() {
+        assert!(BCH::<5>::from_distance(3).is_ok());
+        let expected = CodingError::InvalidParameters("Code length 2^M - 1 should be at most 31");
+        assert_eq!(BCH::<6>::from_distance(3).unwrap_err(), expected);
+        assert_eq!(BCH::<6>::from_max_errors(1).unwrap_err(), expected);
+        assert_eq!(BCH::<6>::from_params(0, 5).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_too_small_distance() {
+        assert!(BCH::<4>::from_distance(0).is_err());
+        assert!(BCH::<4>::from_distance(1).is_err());
+        assert!(BCH::<4>::from_distance(2).is_err());
+        assert!(BCH::<4>::from_max_errors(0).is_err());
     }
 }
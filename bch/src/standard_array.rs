@@ -0,0 +1,202 @@
+use crate::cyclic::next_combination;
+use crate::{CodingError, DecodeResult};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use galois::{Matrix, PolyGF2, GF2TM};
+use num_traits::{One, Zero};
+
+/// Largest number of independent parity checks, i.e. `log2` of the number of cosets,
+/// for which `StandardArrayDecoder` builds its table.
+pub const STANDARD_ARRAY_MAX_REDUNDANCY: usize = 20;
+
+/// Syndrome decoding by table lookup for small binary linear codes: every syndrome maps to
+/// a minimum-weight error pattern of its coset, found by enumerating patterns by weight.
+///
+/// Corrects every error pattern that is a coset leader, so it is a maximum-likelihood
+/// decoder on the binary symmetric channel. The message is read off the information set,
+/// the positions outside the pivot columns of the row-reduced parity-check matrix; for
+/// `HammingCode::parity_check_matrix` these are exactly the data positions of the code.
+#[derive(Debug, Clone)]
+pub struct StandardArrayDecoder {
+    // Row i of the parity-check matrix as a bit mask over the code positions
+    parity_checks: Vec<u32>,
+    code_length: usize,
+    information_set: Vec<usize>,
+    coset_leaders: BTreeMap<u32, u32>,
+}
+
+impl StandardArrayDecoder {
+    /// Builds the table from a binary parity-check matrix, whose entries must be 0 or 1.
+    pub fn new<const M: u32>(parity_check: &Matrix<GF2TM<M>>) -> Result<Self, CodingError> {
+        let code_length = parity_check.cols();
+        if code_length == 0 || code_length > 31 {
+            return Err(CodingError::InvalidParameters(
+                "Code length should be between 1 and 31",
+            ));
+        }
+        if parity_check.rows() > 32 {
+            return Err(CodingError::InvalidParameters(
+                "Parity-check matrix should have at most 32 rows",
+            ));
+        }
+        let mut parity_checks = Vec::with_capacity(parity_check.rows());
+        for i in 0..parity_check.rows() {
+            let mut mask = 0;
+            for j in 0..code_length {
+                let entry = parity_check[[i, j]];
+                if !entry.is_zero() && !entry.is_one() {
+                    return Err(CodingError::InvalidParameters(
+                        "Parity-check matrix should be binary",
+                    ));
+                }
+                mask |= (entry.value().poly) << j;
+            }
+            parity_checks.push(mask);
+        }
+        let (_, pivots) = parity_check.rref();
+        let redundancy = pivots.len();
+        if redundancy > STANDARD_ARRAY_MAX_REDUNDANCY {
+            return Err(CodingError::InvalidParameters(
+                "Too many cosets for a standard array",
+            ));
+        }
+
+        let mut decoder = StandardArrayDecoder {
+            parity_checks,
+            code_length,
+            information_set: (0..code_length).filter(|j| !pivots.contains(j)).collect(),
+            coset_leaders: BTreeMap::new(),
+        };
+        // Patterns in order of increasing weight, the first one seen in a coset leads it
+        let cosets = 1usize << redundancy;
+        'weights: for weight in 0..=code_length {
+            let mut pattern = (1u32 << weight) - 1;
+            while pattern >> code_length == 0 {
+                let syndrome = decoder.syndrome(PolyGF2::new(pattern));
+                decoder.coset_leaders.entry(syndrome).or_insert(pattern);
+                if decoder.coset_leaders.len() == cosets {
+                    break 'weights;
+                }
+                if pattern == 0 {
+                    break;
+                }
+                pattern = next_combination(pattern);
+            }
+        }
+        Ok(decoder)
+    }
+
+    pub fn code_length(&self) -> usize {
+        self.code_length
+    }
+
+    /// Positions holding the message bits of a codeword, lowest first.
+    pub fn information_set(&self) -> &[usize] {
+        &self.information_set
+    }
+
+    /// Bit `i` is the parity of the received word under parity check `i`.
+    pub fn syndrome(&self, received: PolyGF2) -> u32 {
+        self.parity_checks
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, check)| {
+                acc | ((check & received.poly).count_ones() & 1) << i
+            })
+    }
+
+    pub fn coset_leader(&self, syndrome: u32) -> Option<PolyGF2> {
+        self.coset_leaders.get(&syndrome).copied().map(PolyGF2::new)
+    }
+
+    /// Message of the codeword closest to `received`, which is obtained by removing the
+    /// leader of its coset, together with the positions of that leader.
+    pub fn decode(&self, received: PolyGF2) -> Result<DecodeResult, CodingError> {
+        if received.poly >> self.code_length != 0 {
+            return Err(CodingError::WrongLength);
+        }
+        let leader = self
+            .coset_leader(self.syndrome(received))
+            .ok_or(CodingError::Uncorrectable)?;
+        let error_positions = (0..self.code_length)
+            .filter(|i| leader.poly >> i & 1 == 1)
+            .collect();
+        let corrected = received + leader;
+        let message = self
+            .information_set
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, j)| acc | (corrected.poly >> j & 1) << i);
+        Ok(DecodeResult::new(PolyGF2::new(message), error_positions, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HammingCode, BCH};
+
+    #[test]
+    fn test_hamming_single_errors() {
+        let hamming = HammingCode::<3>::new().unwrap();
+        let decoder = StandardArrayDecoder::new(&hamming.parity_check_matrix()).unwrap();
+        // A perfect code: the leaders are exactly the 8 patterns of weight at most 1
+        assert_eq!(decoder.coset_leaders.len(), 8);
+        assert!(decoder.coset_leaders.values().all(|e| e.count_ones() <= 1));
+
+        assert_eq!(decoder.information_set(), [2, 4, 5, 6]);
+
+        for message in 0..16 {
+            let message = PolyGF2::new(message);
+            let encoded = hamming.encode(message).unwrap();
+            assert_eq!(decoder.syndrome(encoded), 0);
+            assert_eq!(decoder.decode(encoded).unwrap().message(), &message);
+            for i in 0..7 {
+                let received = PolyGF2::new(encoded.poly ^ 1 << i);
+                assert_eq!(decoder.decode(received).unwrap().message(), &message);
+                let result = decoder.decode(received).unwrap();
+                assert_eq!(result.error_positions, vec![i]);
+                assert_eq!(hamming.decode(received).unwrap().message(), &message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_bch() {
+        const M: u32 = 4;
+        let bch = BCH::<M>::from_distance(5).unwrap();
+        // The binary parity checks behind the systematic generator span the dual code
+        let generator = bch.systematic_generator_matrix();
+        let k = generator.rows();
+        let parity = generator.submatrix(0..k, k..15).transpose();
+        let parity_check = parity.hstack(&Matrix::identity(15 - k));
+        assert_eq!(
+            generator.matmul(&parity_check.transpose()),
+            Matrix::zero(k, 15 - k)
+        );
+
+        let decoder = StandardArrayDecoder::new(&parity_check).unwrap();
+        assert_eq!(decoder.information_set().len(), k);
+        let codeword = bch.encode(PolyGF2::new(0b1011001)).unwrap();
+        let message = decoder.decode(codeword).unwrap().message;
+        for a in 0..15 {
+            for b in a..15 {
+                let received = PolyGF2::new(codeword.poly ^ 1 << a ^ 1 << b);
+                assert_eq!(decoder.decode(received).unwrap().message(), &message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_matrix() {
+        let g = |x: u32| GF2TM::<4>::from(x);
+        let matrix = Matrix::from_rows(vec![vec![g(1), g(2), g(0)]]);
+        assert!(StandardArrayDecoder::new(&matrix).is_err());
+        let hamming = HammingCode::<3>::new().unwrap();
+        let decoder = StandardArrayDecoder::new(&hamming.parity_check_matrix()).unwrap();
+        assert_eq!(
+            decoder.decode(PolyGF2::new(1 << 7)),
+            Err(CodingError::WrongLength)
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use core::fmt::{Display, Formatter};
+
+/// Errors reported by the encoders and decoders built on top of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodingError {
+    /// The message does not fit into a single codeword.
+    MessageTooLong,
+    /// The input does not have the length the code expects.
+    WrongLength,
+    /// More errors occurred than the code can correct.
+    Uncorrectable,
+    /// The requested code parameters are not supported.
+    InvalidParameters(&'static str),
+}
+
+impl Display for CodingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CodingError::MessageTooLong => write!(f, "Message is too long"),
+            CodingError::WrongLength => write!(f, "Received message has wrong length"),
+            CodingError::Uncorrectable => write!(f, "uncorrectable"),
+            CodingError::InvalidParameters(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodingError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            CodingError::MessageTooLong.to_string(),
+            "Message is too long"
+        );
+        assert_eq!(
+            CodingError::WrongLength.to_string(),
+            "Received message has wrong length"
+        );
+        assert_eq!(CodingError::Uncorrectable.to_string(), "uncorrectable");
+        assert_eq!(
+            CodingError::InvalidParameters("Distance should be at least 3").to_string(),
+            "Distance should be at least 3"
+        );
+    }
+}
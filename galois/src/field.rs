@@ -0,0 +1,86 @@
+use crate::matrix::MatrixElement;
+use crate::GF2TM;
+use core::fmt::Debug;
+use core::ops::{Div, Neg};
+use num_traits::{One, Zero};
+
+/// Common interface of finite fields, so that algorithms can be written once for every field.
+///
+/// `zero`, `one` and the field operations come from the `Zero`, `One` and operator supertraits.
+pub trait FiniteField:
+    MatrixElement + Zero + One + Div<Output = Self> + Neg<Output = Self> + Eq + Debug
+{
+    /// Multiplicative inverse, or zero for the zero element.
+    fn inv(&self) -> Self;
+
+    fn pow(&self, exp: u32) -> Self;
+
+    /// The prime p such that p * x = 0 for every element x.
+    fn characteristic() -> u32;
+
+    /// Number of elements of the field.
+    fn order() -> u32;
+}
+
+impl<const M: u32> FiniteField for GF2TM<M> {
+    fn inv(&self) -> Self {
+        GF2TM::inv(self)
+    }
+
+    fn pow(&self, exp: u32) -> Self {
+        GF2TM::pow(self, exp)
+    }
+
+    fn characteristic() -> u32 {
+        2
+    }
+
+    fn order() -> u32 {
+        1 << M
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FiniteField;
+    use crate::GF2TM;
+
+    // Field axioms on every element, or on the first 256 ones for larger fields
+    fn check_field<F: FiniteField>() {
+        let elements: Vec<F> = (0..F::order().min(256)).map(|i| F::from(i as u8)).collect();
+        let (zero, one) = (F::zero(), F::one());
+        assert!(zero != one);
+
+        let mut sum = zero;
+        for _ in 0..F::characteristic() {
+            sum += one;
+        }
+        assert_eq!(sum, zero);
+
+        for &a in &elements {
+            assert_eq!(a + zero, a);
+            assert_eq!(a * one, a);
+            assert_eq!(a + -a, zero);
+            assert_eq!(a.pow(F::order()), a);
+            if a != zero {
+                assert_eq!(a * a.inv(), one);
+                assert_eq!(a.pow(F::order() - 1), one);
+            }
+            for &b in &elements {
+                assert_eq!(a + b, b + a);
+                assert_eq!(a * b, b * a);
+                if b != zero {
+                    assert_eq!(a / b * b, a);
+                }
+            }
+        }
+        assert_eq!(zero.inv(), zero);
+    }
+
+    #[test]
+    fn test_gf2tm_is_field() {
+        check_field::<GF2TM<2>>();
+        check_field::<GF2TM<4>>();
+        check_field::<GF2TM<8>>();
+    }
+}
@@ -1,50 +1,214 @@
 use crate::matrix::MatrixElement;
+#[cfg(feature = "std")]
+use crate::CodingError;
 use crate::PolyGF2;
-use num_traits::{One, Zero};
-use polynomial::Polynomial;
-use std::cmp::Ordering;
-use std::collections::HashSet;
-use std::ops::{
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
+use num_traits::{One, Zero};
+#[cfg(feature = "std")]
+use polynomial::Polynomial;
 
-#[derive(Eq, Hash, Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug)]
 pub struct GF2TM<const M: u32> {
     value: PolyGF2,
     irr: PolyGF2,
 }
 
+// Elements are identified by their reduced representative only, the modulus is
+// bookkeeping for arithmetic and must not split equal elements apart.
+impl<const M: u32> PartialEq for GF2TM<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const M: u32> Eq for GF2TM<M> {}
+
+impl<const M: u32> Hash for GF2TM<M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
 impl<const M: u32> Default for GF2TM<M> {
     fn default() -> GF2TM<M> {
         GF2TM {
             value: PolyGF2::default(),
-            irr: PolyGF2::irreducible(M),
+            irr: default_irr::<M>(),
         }
     }
 }
 
 impl<const M: u32> GF2TM<M> {
     pub fn new(value: PolyGF2) -> GF2TM<M> {
-        GF2TM {
-            value: value % PolyGF2::new(1 << M),
-            irr: PolyGF2::irreducible(M),
-        }
+        GF2TM::with_irr(value, default_irr::<M>())
     }
+
     pub fn one() -> GF2TM<M> {
         GF2TM {
             value: PolyGF2::new(1),
-            irr: PolyGF2::irreducible(M),
+            irr: default_irr::<M>(),
+        }
+    }
+
+    /// Element `value mod irr`. `irr` must be an irreducible polynomial of degree M, which is
+    /// not checked here; build elements through `GF2TMField` to have it validated once.
+    pub fn with_irr(value: PolyGF2, irr: PolyGF2) -> GF2TM<M> {
+        check_degree::<M>();
+        GF2TM {
+            value: value % irr,
+            irr,
         }
     }
 
     pub fn value(&self) -> PolyGF2 {
         self.value
     }
+
+    pub fn irr(&self) -> PolyGF2 {
+        self.irr
+    }
+
+    // Constants built through `Zero`, `One` or `From` carry the default modulus,
+    // so a custom modulus on either side of an operation takes precedence. Two different
+    // custom moduli are two different fields, and mixing them is a bug.
+    fn common_irr(&self, rhs: &Self) -> PolyGF2 {
+        if self.irr == rhs.irr || rhs.irr == default_irr::<M>() {
+            self.irr
+        } else if self.irr == default_irr::<M>() {
+            rhs.irr
+        } else {
+            panic!("Cannot combine elements of fields with different moduli");
+        }
+    }
+}
+
+// M = 1 has no primitive element other than 1 and M >= 31 overflows the u32 arithmetic
+fn check_degree<const M: u32>() {
+    if !(2..=30).contains(&M) {
+        panic!("Field degree M must be between 2 and 30");
+    }
+}
+
+fn default_irr<const M: u32>() -> PolyGF2 {
+    check_degree::<M>();
+    PolyGF2::irreducible(M)
+}
+
+/// The field GF(2^M) represented as GF(2)[x] modulo a chosen irreducible polynomial of degree M.
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Debug)]
+pub struct GF2TMField<const M: u32> {
+    irr: PolyGF2,
+}
+
+impl<const M: u32> Default for GF2TMField<M> {
+    fn default() -> GF2TMField<M> {
+        GF2TMField {
+            irr: default_irr::<M>(),
+        }
+    }
+}
+
+impl<const M: u32> GF2TMField<M> {
+    /// # Panics
+    ///
+    /// Panics if `irr` is not an irreducible polynomial of degree M.
+    pub fn new(irr: PolyGF2) -> GF2TMField<M> {
+        check_degree::<M>();
+        if irr.degree() != M as usize {
+            panic!("Modulus degree must be equal to M");
+        }
+        if !irr.is_irreducible() {
+            panic!("Modulus must be irreducible");
+        }
+        GF2TMField { irr }
+    }
+
+    pub fn irr(&self) -> PolyGF2 {
+        self.irr
+    }
+
+    pub fn elem(&self, value: PolyGF2) -> GF2TM<M> {
+        GF2TM::with_irr(value, self.irr)
+    }
+
+    pub fn zero(&self) -> GF2TM<M> {
+        self.elem(PolyGF2::default())
+    }
+
+    pub fn one(&self) -> GF2TM<M> {
+        self.elem(PolyGF2::new(1))
+    }
+
+    pub fn primitive_element(&self) -> GF2TM<M> {
+        for candidate in 1..(1 << M) {
+            let alpha = self.elem(PolyGF2::new(candidate));
+            if alpha.is_primitive() {
+                return alpha;
+            }
+        }
+        unreachable!("Invalid irreducible polynomial");
+    }
+
+    pub fn from_power(&self, i: u32) -> GF2TM<M> {
+        let i = i % ((1 << M) - 1);
+        // The tables are generated by the same α as `primitive_element`
+        #[cfg(feature = "std")]
+        if let Some(tables) = log_tables::<M>().filter(|tables| tables.irr == self.irr) {
+            return self.elem(tables.exp(i as usize));
+        }
+        self.primitive_element().pow(i)
+    }
+
+    // Inverse of `from_power`: i in 0..2^M - 1 with α^i = x, None for zero
+    fn log(&self, x: GF2TM<M>) -> Option<u32> {
+        if x.value.is_zero() {
+            return None;
+        }
+        #[cfg(feature = "std")]
+        if let Some(tables) = log_tables::<M>().filter(|tables| tables.irr == self.irr) {
+            return Some(tables.log[x.value.poly as usize] as u32);
+        }
+        let alpha = self.primitive_element();
+        let mut power = self.one();
+        for i in 0..(1 << M) - 1 {
+            if power.value == x.value {
+                return Some(i);
+            }
+            power *= alpha;
+        }
+        unreachable!("Invalid irreducible polynomial");
+    }
 }
 
+// Written as a power of the primitive element of the element's own field: 0, 1, α or α^i
+impl<const M: u32> Display for GF2TM<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (GF2TMField { irr: self.irr }).log(*self) {
+            None => write!(f, "0"),
+            Some(0) => write!(f, "1"),
+            Some(1) => write!(f, "α"),
+            Some(i) => write!(f, "α^{i}"),
+        }
+    }
+}
+
+// Ordered by reduced representative, consistently with `Eq`
 impl<const M: u32> PartialOrd for GF2TM<M> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
+        Some(self.cmp(other))
+    }
+}
+
+impl<const M: u32> Ord for GF2TM<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
     }
 }
 
@@ -52,9 +216,10 @@ impl<const M: u32> Add for GF2TM<M> {
     type Output = GF2TM<M>;
 
     fn add(self, rhs: Self) -> Self::Output {
+        let irr = self.common_irr(&rhs);
         GF2TM {
-            value: (self.value + rhs.value) % self.irr,
-            irr: self.irr,
+            value: (self.value + rhs.value) % irr,
+            irr,
         }
     }
 }
@@ -69,9 +234,10 @@ impl<const M: u32> Sub for GF2TM<M> {
     type Output = GF2TM<M>;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        let irr = self.common_irr(&rhs);
         GF2TM {
-            value: (self.value - rhs.value) % self.irr,
-            irr: self.irr,
+            value: (self.value - rhs.value) % irr,
+            irr,
         }
     }
 }
@@ -86,9 +252,17 @@ impl<const M: u32> Mul for GF2TM<M> {
     type Output = GF2TM<M>;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        let irr = self.common_irr(&rhs);
+        #[cfg(feature = "std")]
+        if let Some(tables) = log_tables::<M>().filter(|tables| tables.irr == irr) {
+            return GF2TM {
+                value: tables.mul(self.value, rhs.value),
+                irr,
+            };
+        }
         GF2TM {
-            value: (self.value * rhs.value) % self.irr,
-            irr: self.irr,
+            value: (self.value * rhs.value) % irr,
+            irr,
         }
     }
 }
@@ -103,9 +277,10 @@ impl<const M: u32> Div for GF2TM<M> {
     type Output = GF2TM<M>;
 
     fn div(self, rhs: Self) -> Self::Output {
+        let irr = self.common_irr(&rhs);
         GF2TM {
-            value: (self.value * rhs.inv().value) % self.irr,
-            irr: self.irr,
+            value: (self.value * GF2TM::<M>::with_irr(rhs.value, irr).inv().value) % irr,
+            irr,
         }
     }
 }
@@ -116,13 +291,21 @@ impl<const M: u32> DivAssign for GF2TM<M> {
     }
 }
 
+/// Remainder in a field: every nonzero divisor divides exactly, so the result is always zero.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
 impl<const M: u32> Rem for GF2TM<M> {
     type Output = GF2TM<M>;
 
     fn rem(self, rhs: Self) -> Self::Output {
+        if rhs.is_zero() {
+            panic!("division by zero");
+        }
         GF2TM {
-            value: (self.value % rhs.value) % self.irr,
-            irr: self.irr,
+            value: PolyGF2::default(),
+            irr: self.common_irr(&rhs),
         }
     }
 }
@@ -146,10 +329,7 @@ impl<const M: u32> Neg for GF2TM<M> {
 
 impl<const M: u32> From<u32> for GF2TM<M> {
     fn from(poly: u32) -> Self {
-        GF2TM {
-            value: PolyGF2::new(poly),
-            irr: PolyGF2::irreducible(M),
-        }
+        GF2TM::new(PolyGF2::new(poly))
     }
 }
 impl<const M: u32> From<u8> for GF2TM<M> {
@@ -162,7 +342,7 @@ impl<const M: u32> Zero for GF2TM<M> {
     fn zero() -> Self {
         GF2TM {
             value: PolyGF2::default(),
-            irr: PolyGF2::irreducible(M),
+            irr: default_irr::<M>(),
         }
     }
 
@@ -174,7 +354,7 @@ impl<const M: u32> One for GF2TM<M> {
     fn one() -> Self {
         GF2TM {
             value: PolyGF2::new(1),
-            irr: PolyGF2::irreducible(M),
+            irr: default_irr::<M>(),
         }
     }
 
@@ -185,8 +365,19 @@ impl<const M: u32> One for GF2TM<M> {
 
 impl<const M: u32> GF2TM<M> {
     pub fn pow(&self, exp: u32) -> GF2TM<M> {
+        // Square-and-multiply, reducing at every step so the representative never overflows
+        let mut result = PolyGF2::new(1) % self.irr;
+        let mut base = self.value;
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = (result * base) % self.irr;
+            }
+            base = (base * base) % self.irr;
+            exp >>= 1;
+        }
         GF2TM {
-            value: self.value.pow(exp) % self.irr,
+            value: result,
             irr: self.irr,
         }
     }
@@ -195,16 +386,52 @@ impl<const M: u32> GF2TM<M> {
         self.pow((1 << M) - 2)
     }
 
+    /// Inverts every element with a single `inv` and O(n) multiplications (Montgomery's trick).
+    /// Zero elements map to zero, like with `inv`.
+    pub fn batch_inv(elems: &[GF2TM<M>]) -> Vec<GF2TM<M>> {
+        // prefix[i] is the product of the nonzero elements before position i
+        let mut prefix = Vec::with_capacity(elems.len());
+        let mut product = GF2TM::<M>::one();
+        for &elem in elems {
+            prefix.push(product);
+            if !elem.is_zero() {
+                product *= elem;
+            }
+        }
+        let mut inv = product.inv();
+        let mut result = vec![GF2TM::zero(); elems.len()];
+        for (i, &elem) in elems.iter().enumerate().rev() {
+            if !elem.is_zero() {
+                result[i] = inv * prefix[i];
+                inv *= elem;
+            }
+        }
+        result
+    }
+
     pub fn minimal_poly(&self) -> PolyGF2 {
-        let mut conjugates = HashSet::new();
-        for i in 0..M {
-            let new = self.pow(2_u32.pow(i));
-            conjugates.insert(new);
+        // Product of (x - c) over the distinct conjugates c = a, a^2, a^4, ...
+        let mut conjugates = vec![*self];
+        let mut conjugate = self.pow(2);
+        while conjugate != *self {
+            conjugates.push(conjugate);
+            conjugate = conjugate.pow(2);
+        }
+
+        let mut product: Vec<GF2TM<M>> = vec![GF2TM::one()];
+        for c in conjugates {
+            let mut next = vec![GF2TM::zero(); product.len() + 1];
+            for (i, p) in product.iter().enumerate() {
+                next[i + 1] += *p;
+                next[i] -= c * *p;
+            }
+            product = next;
         }
-        conjugates
+        // The coefficients lie in GF(2), so only their lowest bit is set
+        product
             .iter()
-            .map(|a| Polynomial::new(vec![*a, GF2TM::one()]))
-            .fold(Polynomial::new(vec![GF2TM::one()]), |acc, e| acc * e)
+            .rev()
+            .fold(0, |acc, x| acc << 1 | x.value().poly & 1)
             .into()
     }
 
@@ -223,22 +450,222 @@ impl<const M: u32> GF2TM<M> {
         powers == PolyGF2::new(1)
     }
 
+    pub fn is_in_subfield(&self, k: u32) -> bool {
+        // GF(2^k) is a subfield iff k | M, and its elements are exactly the roots of x^(2^k) - x
+        if k == 0 || !M.is_multiple_of(k) {
+            return false;
+        }
+        self.pow(1 << k) == *self
+    }
+
+    /// Lifts the element into GF(2^MK) through the subfield inclusion GF(2^M) ⊂ GF(2^MK).
+    ///
+    /// The polynomial basis {1, θ, ..., θ^(M-1)} is mapped onto powers of θ, the numerically
+    /// smallest root of this element's modulus in GF(2^MK) (with the default modulus of MK).
+    /// Any root gives a valid embedding; picking the smallest one makes it deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if M does not divide MK.
+    pub fn embed<const MK: u32>(&self) -> GF2TM<MK> {
+        let theta = subfield_root::<MK>(self.irr, default_irr::<MK>());
+        eval_at(self.value, theta)
+    }
+
+    /// Inverse of [`GF2TM::embed`]: maps an element of the subfield GF(2^K) ⊂ GF(2^M) back
+    /// to GF(2^K) with its default modulus, or returns `None` if the element does not lie in
+    /// that subfield. The root θ is searched in the element's own field, so elements built
+    /// with a custom modulus project consistently with their own representation.
+    pub fn project<const K: u32>(&self) -> Option<GF2TM<K>> {
+        if !self.is_in_subfield(K) {
+            return None;
+        }
+        let theta = subfield_root::<M>(default_irr::<K>(), self.irr);
+        (0..1 << K)
+            .map(PolyGF2::new)
+            .find(|&candidate| eval_at(candidate, theta).value == self.value)
+            .map(GF2TM::new)
+    }
+
     pub fn primitive_element() -> GF2TM<M> {
-        for candidate in 1..(1 << M) {
-            let alpha = GF2TM::new(PolyGF2::new(candidate));
-            if alpha.is_primitive() {
-                return alpha;
+        GF2TMField::default().primitive_element()
+    }
+
+    pub fn from_power(i: u32) -> GF2TM<M> {
+        // α^i, the exponent is taken modulo the multiplicative group order 2^M - 1
+        GF2TMField::default().from_power(i)
+    }
+}
+
+impl<const M: u32> MatrixElement for GF2TM<M> {}
+
+#[cfg(feature = "std")]
+const LOG_TABLES_MAX_M: u32 = 16;
+
+// Built on first use for the default modulus of every M <= LOG_TABLES_MAX_M
+#[cfg(feature = "std")]
+static LOG_TABLES: [std::sync::OnceLock<LogTables>; LOG_TABLES_MAX_M as usize + 1] =
+    [const { std::sync::OnceLock::new() }; LOG_TABLES_MAX_M as usize + 1];
+
+#[cfg(feature = "std")]
+fn log_tables<const M: u32>() -> Option<&'static LogTables> {
+    if M > LOG_TABLES_MAX_M {
+        return None;
+    }
+    Some(LOG_TABLES[M as usize].get_or_init(|| LogTables::new(M, default_irr::<M>())))
+}
+
+/// Discrete logarithms and powers of a generator of GF(2^m)*, turning a product into
+/// two lookups and an index addition.
+#[cfg(feature = "std")]
+struct LogTables {
+    irr: PolyGF2,
+    log: Vec<u16>,
+    // Two periods long, so the sum of two logarithms needs no reduction
+    exp: Vec<u16>,
+}
+
+#[cfg(feature = "std")]
+impl LogTables {
+    // Works with raw polynomials: `GF2TM` multiplication would come back here
+    fn new(m: u32, irr: PolyGF2) -> LogTables {
+        let order = (1 << m) - 1;
+        for generator in 2..=order as u32 {
+            let mut log = vec![u16::MAX; order + 1];
+            let mut exp = vec![0; 2 * order];
+            let mut power = PolyGF2::new(1);
+            let mut i = 0;
+            while i < order && log[power.poly as usize] == u16::MAX {
+                log[power.poly as usize] = i as u16;
+                exp[i] = power.poly as u16;
+                exp[i + order] = power.poly as u16;
+                power = (power * PolyGF2::new(generator)) % irr;
+                i += 1;
             }
+            if i == order {
+                return LogTables { irr, log, exp };
+            }
+        }
+        unreachable!("Invalid irreducible polynomial");
+    }
+
+    fn mul(&self, a: PolyGF2, b: PolyGF2) -> PolyGF2 {
+        if a.is_zero() || b.is_zero() {
+            return PolyGF2::default();
         }
-        unreachable!("Invalid irreducible polynomial, use M > 1");
+        let index = self.log[a.poly as usize] as usize + self.log[b.poly as usize] as usize;
+        PolyGF2::new(self.exp[index] as u32)
+    }
+
+    fn exp(&self, i: usize) -> PolyGF2 {
+        PolyGF2::new(self.exp[i] as u32)
     }
 }
 
-impl<const M: u32> MatrixElement for GF2TM<M> {}
+// Serialized as the reduced value together with M; the modulus is re-derived on the way back,
+// so elements of a field with a custom irreducible come back with the default one.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GF2TMRepr {
+    value: u32,
+    m: u32,
+}
+
+#[cfg(feature = "serde")]
+impl<const M: u32> serde::Serialize for GF2TM<M> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GF2TMRepr {
+            value: self.value.poly,
+            m: M,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const M: u32> serde::Deserialize<'de> for GF2TM<M> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let repr = GF2TMRepr::deserialize(deserializer)?;
+        if repr.m != M {
+            return Err(D::Error::custom(alloc::format!(
+                "expected an element of GF(2^{M}), got GF(2^{})",
+                repr.m
+            )));
+        }
+        if repr.value >> M != 0 {
+            return Err(D::Error::custom("value is not reduced"));
+        }
+        Ok(GF2TM::new(PolyGF2::new(repr.value)))
+    }
+}
+
+/// Lagrange interpolation: the unique polynomial of degree below `points.len()` through all
+/// `(x, y)` pairs, Σ y_j Π_(m != j) (x - x_m) / (x_j - x_m).
+#[cfg(feature = "std")]
+pub fn interpolate<const M: u32>(
+    points: &[(GF2TM<M>, GF2TM<M>)],
+) -> Result<Polynomial<GF2TM<M>>, CodingError> {
+    let mut coefficients = vec![GF2TM::zero(); points.len()];
+    for (j, &(xj, yj)) in points.iter().enumerate() {
+        let mut basis = vec![GF2TM::one()];
+        let mut denominator = GF2TM::one();
+        for (m, &(xm, _)) in points.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+            if xm == xj {
+                return Err(CodingError::InvalidParameters(
+                    "Interpolation points must have distinct x-coordinates",
+                ));
+            }
+            // basis *= x - x_m
+            let mut next = vec![GF2TM::zero(); basis.len() + 1];
+            for (i, coefficient) in basis.iter().enumerate() {
+                next[i + 1] += *coefficient;
+                next[i] -= *coefficient * xm;
+            }
+            basis = next;
+            denominator *= xj - xm;
+        }
+        let scale = yj / denominator;
+        for (coefficient, b) in coefficients.iter_mut().zip(&basis) {
+            *coefficient += *b * scale;
+        }
+    }
+    Ok(Polynomial::new(coefficients))
+}
+
+// Evaluates a binary polynomial at a point of GF(2^N) using Horner's rule
+fn eval_at<const N: u32>(poly: PolyGF2, x: GF2TM<N>) -> GF2TM<N> {
+    let one = GF2TM::with_irr(PolyGF2::new(1), x.irr);
+    let mut result = GF2TM::with_irr(PolyGF2::default(), x.irr);
+    for i in (0..=poly.degree().min(31)).rev() {
+        result *= x;
+        if poly.poly >> i & 1 == 1 {
+            result += one;
+        }
+    }
+    result
+}
+
+// Smallest root of an irreducible polynomial whose degree divides N, in GF(2^N) represented
+// modulo `modulus`
+fn subfield_root<const N: u32>(irr: PolyGF2, modulus: PolyGF2) -> GF2TM<N> {
+    if irr.degree() == 0 || !(N as usize).is_multiple_of(irr.degree()) {
+        panic!("Field degree must be a multiple of the subfield degree");
+    }
+    (1..1 << N)
+        .map(|candidate| GF2TM::with_irr(PolyGF2::new(candidate), modulus))
+        .find(|&candidate| eval_at(irr, candidate).is_zero())
+        .unwrap()
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::{BTreeSet, HashSet};
 
     #[test]
     fn test_minimal_poly() {
@@ -282,4 +709,348 @@ mod tests {
         assert!(GF2TM::<2>::from(0b11u32).is_primitive());
         assert!(GF2TM::<3>::from(0b11u32).is_primitive());
     }
+
+    #[test]
+    fn test_display() {
+        let alpha = GF2TM::<4>::primitive_element();
+        assert_eq!(GF2TM::<4>::zero().to_string(), "0");
+        assert_eq!(GF2TM::<4>::one().to_string(), "1");
+        assert_eq!(alpha.to_string(), "α");
+        assert_eq!(GF2TM::<4>::from(0b0011u32).to_string(), "α^4");
+        assert_eq!(alpha.inv().to_string(), "α^14");
+
+        // Powers of the primitive element of the element's own field
+        let field = GF2TMField::<4>::new(PolyGF2::new(0b11001));
+        assert_eq!(field.from_power(9).to_string(), "α^9");
+        assert_eq!(GF2TM::<8>::from_power(200).to_string(), "α^200");
+    }
+
+    #[test]
+    fn test_from_power() {
+        assert_eq!(GF2TM::<4>::from_power(0), GF2TM::one());
+        assert_eq!(GF2TM::<4>::from_power((1 << 4) - 1), GF2TM::one());
+        assert_eq!(GF2TM::<4>::from_power(1), GF2TM::primitive_element());
+        assert_eq!(GF2TM::<4>::from_power(4), GF2TM::from(0b0011u32));
+        assert_eq!(GF2TM::<4>::from_power(19), GF2TM::<4>::from_power(4));
+
+        for field in [
+            GF2TMField::<4>::default(),
+            GF2TMField::new(PolyGF2::new(0b11001)),
+        ] {
+            let alpha = field.primitive_element();
+            for i in 0..20 {
+                assert_eq!(field.from_power(i), alpha.pow(i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_irreducible() {
+        // x^4 + x^3 + 1 instead of the default x^4 + x + 1
+        let field = GF2TMField::<4>::new(PolyGF2::new(0b11001));
+        let x = field.elem(PolyGF2::new(0b10));
+        let x3 = field.elem(PolyGF2::new(0b1000));
+        assert_eq!(x * x3, field.elem(PolyGF2::new(0b1001)));
+        assert_eq!(
+            GF2TM::<4>::from(0b10u32) * GF2TM::from(0b1000u32),
+            GF2TM::from(0b0011u32)
+        );
+
+        assert_eq!(
+            GF2TM::<4>::with_irr(PolyGF2::new(0b10000), PolyGF2::new(0b11001)),
+            field.elem(PolyGF2::new(0b1001))
+        );
+
+        for a in 1..16 {
+            let a = field.elem(PolyGF2::new(a));
+            assert_eq!(a * a.inv(), field.one());
+            assert_eq!(a / a, field.one());
+            for b in 1..16 {
+                let b = field.elem(PolyGF2::new(b));
+                assert_eq!((a * b) / b, a);
+                assert_eq!(a * (b + x), a * b + a * x);
+            }
+        }
+
+        let alpha = field.primitive_element();
+        assert_eq!(alpha.irr(), field.irr());
+        assert_eq!(field.from_power(15), field.one());
+        assert_eq!(alpha + GF2TM::zero(), alpha);
+        assert_eq!(alpha * GF2TM::one(), alpha);
+    }
+
+    #[test]
+    fn test_eq_hash_by_value() {
+        let mut set = HashSet::new();
+        set.insert(GF2TM::<4>::new(PolyGF2::new(0)));
+        set.insert(GF2TM::<4>::new(PolyGF2::new(1)));
+        set.insert(GF2TM::<4>::new(PolyGF2::new(0b110)));
+        set.insert(GF2TM::<4>::from(0u32));
+        set.insert(GF2TM::<4>::from(1u8));
+        set.insert(GF2TM::<4>::from(0b110u32));
+        set.insert(GF2TM::<4>::zero());
+        set.insert(GF2TM::<4>::one());
+        set.insert(<GF2TM<4> as One>::one());
+        set.insert(GF2TM::<4>::default());
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&GF2TM::from(0b110u32)));
+
+        let field = GF2TMField::<4>::new(PolyGF2::new(0b11001));
+        assert_eq!(field.one(), GF2TM::one());
+        assert_eq!(field.zero(), GF2TM::zero());
+    }
+
+    #[test]
+    fn test_is_in_subfield() {
+        let gf4: Vec<_> = (0..16u32)
+            .filter(|&a| GF2TM::<4>::from(a).is_in_subfield(2))
+            .collect();
+        assert_eq!(gf4, vec![0, 1, 0b110, 0b111]);
+
+        let gf2: Vec<_> = (0..16u32)
+            .filter(|&a| GF2TM::<4>::from(a).is_in_subfield(1))
+            .collect();
+        assert_eq!(gf2, vec![0, 1]);
+
+        assert!((0..16u32).all(|a| GF2TM::<4>::from(a).is_in_subfield(4)));
+        assert!(!GF2TM::<4>::one().is_in_subfield(3));
+        assert!(!GF2TM::<4>::one().is_in_subfield(0));
+    }
+
+    #[test]
+    fn test_embed() {
+        for a in 0..16u32 {
+            let a = GF2TM::<4>::from(a);
+            let lifted = a.embed::<8>();
+            assert!(lifted.is_in_subfield(4));
+            assert_eq!(lifted.project::<4>(), Some(a));
+            for b in 0..16u32 {
+                let b = GF2TM::<4>::from(b);
+                assert_eq!((a * b).embed::<8>(), lifted * b.embed::<8>());
+                assert_eq!((a + b).embed::<8>(), lifted + b.embed::<8>());
+            }
+        }
+        assert_eq!(GF2TM::<2>::one().embed::<4>(), GF2TM::<4>::one());
+        assert_eq!(GF2TM::<8>::primitive_element().project::<4>(), None);
+
+        // x^8 + x^6 + x^5 + x^4 + 1 instead of the default modulus of GF(2^8)
+        let field = GF2TMField::<8>::new(PolyGF2::new(0b101110001));
+        let subfield: Vec<_> = (0..256)
+            .map(|value| field.elem(PolyGF2::new(value)))
+            .filter(|x| x.is_in_subfield(4))
+            .collect();
+        assert_eq!(subfield.len(), 16);
+        let projected: BTreeSet<_> = subfield.iter().map(|x| x.project::<4>().unwrap()).collect();
+        assert_eq!(projected.len(), 16);
+        for &x in &subfield {
+            for &y in &subfield {
+                let (a, b) = (x.project::<4>().unwrap(), y.project::<4>().unwrap());
+                assert_eq!((x * y).project::<4>(), Some(a * b));
+                assert_eq!((x + y).project::<4>(), Some(a + b));
+            }
+        }
+        assert_eq!(field.primitive_element().project::<4>(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_embed_not_a_subfield() {
+        let _ = GF2TM::<3>::one().embed::<4>();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = GF2TM::<4>::from(0b1011u32);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"value":11,"m":4}"#);
+        let b: GF2TM<4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(b, a);
+        assert_eq!(b.irr(), PolyGF2::irreducible(4));
+
+        assert!(serde_json::from_str::<GF2TM<3>>(&json).is_err());
+        assert!(serde_json::from_str::<GF2TM<4>>(r#"{"value":17,"m":4}"#).is_err());
+    }
+
+    #[test]
+    fn test_rem() {
+        for a in 0..16u32 {
+            for b in 1..16u32 {
+                assert_eq!(GF2TM::<4>::from(a) % GF2TM::from(b), GF2TM::zero());
+            }
+        }
+        let mut a = GF2TM::<4>::from(0b1011u32);
+        a %= GF2TM::from(0b11u32);
+        assert_eq!(a, GF2TM::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rem_by_zero() {
+        let _ = GF2TM::<4>::from(0b1011u32) % GF2TM::zero();
+    }
+
+    #[test]
+    fn test_new_reduces_modulo_irreducible() {
+        // x^4 = x + 1 and x^6 = x^3 + x^2 modulo x^4 + x + 1
+        assert_eq!(
+            GF2TM::<4>::new(PolyGF2::new(0b10000)),
+            GF2TM::from(0b0011u32)
+        );
+        assert_eq!(
+            GF2TM::<4>::new(PolyGF2::new(0b1000000)),
+            GF2TM::from(0b1100u32)
+        );
+        let x = GF2TM::<4>::from(0b10u32);
+        for value in 0..1 << 8 {
+            let reduced = GF2TM::<4>::new(PolyGF2::new(value));
+            assert!(reduced.value().poly >> 4 == 0);
+            let expected = (0..8)
+                .filter(|i| value >> i & 1 == 1)
+                .fold(GF2TM::zero(), |acc, i| acc + x.pow(i));
+            assert_eq!(reduced, expected);
+        }
+    }
+
+    #[test]
+    fn test_degree_bounds() {
+        assert_eq!(GF2TM::<2>::primitive_element(), GF2TM::from(0b10u32));
+        check_degree::<2>();
+        check_degree::<30>();
+        // x^30 + x^6 + x^4 + x + 1; the default modulus of M = 30 is too slow to search for
+        let field = GF2TMField::<30>::new(PolyGF2::new(1 << 30 | 0b1010011));
+        assert_eq!(field.one().value(), PolyGF2::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Field degree M must be between 2 and 30")]
+    fn test_degree_31() {
+        GF2TM::<31>::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "Field degree M must be between 2 and 30")]
+    fn test_degree_zero() {
+        GF2TM::<0>::one();
+    }
+
+    #[test]
+    #[should_panic(expected = "Field degree M must be between 2 and 30")]
+    fn test_degree_one() {
+        let _ = GF2TM::<1>::from(1u32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be irreducible")]
+    fn test_reducible_modulus() {
+        // x^4 + 1 = (x + 1)^4, where x + 1 would have no inverse
+        GF2TMField::<4>::new(PolyGF2::new(0b10001));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine elements of fields with different moduli")]
+    fn test_mixed_moduli() {
+        let a = GF2TMField::<4>::new(PolyGF2::new(0b11001)).elem(PolyGF2::new(0b110));
+        let b = GF2TMField::<4>::new(PolyGF2::new(0b11111)).elem(PolyGF2::new(0b101));
+        let _ = a * b;
+    }
+
+    #[test]
+    #[should_panic(expected = "Field degree M must be between 2 and 30")]
+    fn test_degree_too_large() {
+        GF2TMField::<31>::new(PolyGF2::new(1 << 31 | 0b1001));
+    }
+
+    #[test]
+    fn test_table_mul_matches_polynomial() {
+        fn check<const M: u32>() {
+            let elements: Vec<_> = (0..1u32 << M).map(GF2TM::<M>::from).collect();
+            for a in &elements {
+                for b in &elements {
+                    let expected = (a.value() * b.value()) % a.irr();
+                    assert_eq!((*a * *b).value(), expected, "{a:?} * {b:?}");
+                }
+            }
+        }
+        check::<4>();
+        // x^8 + x^4 + x^3 + x + 1, whose root x is not a generator
+        assert!(!GF2TM::<8>::from(0b10u32).is_primitive());
+        check::<8>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_interpolate() {
+        let alpha = GF2TM::<4>::primitive_element();
+        let message = Polynomial::new(vec![
+            alpha.pow(3),
+            GF2TM::zero(),
+            GF2TM::one(),
+            alpha.pow(11),
+            alpha,
+        ]);
+        // Evaluations at α^0, ..., α^14, as an evaluation-style Reed–Solomon encoder produces them
+        let codeword: Vec<_> = (0..15)
+            .map(|i| (alpha.pow(i), message.eval(alpha.pow(i))))
+            .collect();
+        assert_eq!(interpolate(&codeword), Ok(message.clone()));
+        // Any five of them already determine a polynomial of degree 4, four do not
+        let subset: Vec<_> = codeword.iter().copied().skip(3).step_by(3).collect();
+        assert_eq!(subset.len(), 4);
+        assert_ne!(interpolate(&subset), Ok(message.clone()));
+        let subset: Vec<_> = codeword.iter().copied().step_by(3).collect();
+        assert_eq!(interpolate(&subset), Ok(message));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_interpolate_duplicate_x() {
+        let points = [
+            (GF2TM::<3>::from(1u32), GF2TM::from(2u32)),
+            (GF2TM::from(5u32), GF2TM::from(3u32)),
+            (GF2TM::from(1u32), GF2TM::from(4u32)),
+        ];
+        assert!(interpolate(&points).is_err());
+        assert_eq!(interpolate::<3>(&[]), Ok(Polynomial::new(vec![])));
+    }
+
+    #[test]
+    fn test_batch_inv() {
+        // Pseudo-random elements from the LFSR output, zeros included
+        let mut bits = crate::Lfsr::new(PolyGF2::new(0b10000000000000000000000001001), 1);
+        let elems: Vec<GF2TM<8>> = (0..300)
+            .map(|_| GF2TM::from((0..8).fold(0u32, |acc, _| acc << 1 | bits.next().unwrap())))
+            .collect();
+        assert!(elems.iter().any(|x| x.is_zero()));
+        let expected: Vec<GF2TM<8>> = elems.iter().map(|x| x.inv()).collect();
+        assert_eq!(GF2TM::batch_inv(&elems), expected);
+
+        let field = GF2TMField::<4>::new(PolyGF2::new(0b11111));
+        let elems: Vec<GF2TM<4>> = (0..16).map(|i| field.elem(PolyGF2::new(i))).collect();
+        let inverses = GF2TM::batch_inv(&elems);
+        for (x, y) in elems.iter().zip(&inverses).skip(1) {
+            assert!((*x * *y).is_one());
+        }
+        assert!(inverses[0].is_zero());
+        assert!(GF2TM::<4>::batch_inv(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_ord() {
+        let field = GF2TMField::<4>::new(PolyGF2::new(0b11111));
+        let mut elems: Vec<GF2TM<4>> = (0..16u32).rev().map(GF2TM::from).collect();
+        elems.sort();
+        assert_eq!(elems, (0..16u32).map(GF2TM::from).collect::<Vec<_>>());
+
+        // The modulus does not take part in the comparison
+        let custom = field.elem(PolyGF2::new(9));
+        assert_eq!(custom.cmp(&GF2TM::from(9u32)), Ordering::Equal);
+        let set: BTreeSet<GF2TM<4>> = [custom, GF2TM::from(9u32), GF2TM::from(3u32), field.one()]
+            .into_iter()
+            .chain((0..12).map(|i| GF2TM::from(2u32).pow(1 << (i % 4))))
+            .collect();
+        // The conjugates of x modulo x^4 + x + 1 are x, x^2, x + 1 and x^2 + 1
+        let expected: Vec<GF2TM<4>> = [1u32, 2, 3, 4, 5, 9].map(GF2TM::from).to_vec();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), expected);
+    }
 }
@@ -0,0 +1,99 @@
+use crate::PolyGF2;
+
+/// Fibonacci linear-feedback shift register with characteristic polynomial
+/// p(x) = x^d + c_(d-1) x^(d-1) + ... + c_0, producing s_(n+d) = c_(d-1) s_(n+d-1) + ... + c_0 s_n.
+///
+/// With a primitive p(x) and a nonzero seed the output has the maximal period 2^d - 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lfsr {
+    feedback: PolyGF2,
+    state: u32,
+}
+
+impl Lfsr {
+    /// Bit `i` of `seed` is s_i, the first `d` output bits.
+    pub fn new(feedback: PolyGF2, seed: u32) -> Self {
+        if feedback.poly < 2 {
+            panic!("Feedback polynomial must have positive degree");
+        }
+        let mask = (1u32 << feedback.degree()).wrapping_sub(1);
+        Lfsr {
+            feedback,
+            state: seed & mask,
+        }
+    }
+
+    pub fn feedback(&self) -> PolyGF2 {
+        self.feedback
+    }
+
+    /// The next `d` output bits, the oldest one in bit 0.
+    pub fn state(&self) -> u32 {
+        self.state
+    }
+}
+
+impl Iterator for Lfsr {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let degree = self.feedback.degree();
+        let output = self.state & 1;
+        let feedback = (self.state & self.feedback.poly).count_ones() & 1;
+        self.state = self.state >> 1 | feedback << (degree - 1);
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GF2TM;
+
+    fn period(lfsr: Lfsr) -> Option<usize> {
+        let seed = lfsr.state();
+        let mut lfsr = lfsr;
+        (1..=1 << lfsr.feedback().degree()).find(|_| {
+            lfsr.next();
+            lfsr.state() == seed
+        })
+    }
+
+    #[test]
+    fn test_maximal_length() {
+        // x^4 + x + 1 is primitive
+        let lfsr = Lfsr::new(PolyGF2::new(0b10011), 0b0001);
+        assert_eq!(period(lfsr), Some(15));
+
+        let bits: Vec<u32> = lfsr.take(30).collect();
+        assert_eq!(bits[..15], bits[15..]);
+        assert_eq!(bits[..15].iter().sum::<u32>(), 8);
+        // s_(n+4) = s_(n+1) + s_n
+        for n in 0..26 {
+            assert_eq!(bits[n + 4], bits[n + 1] ^ bits[n]);
+        }
+    }
+
+    #[test]
+    fn test_period_matches_primitivity() {
+        for poly in (0b10001..0b100000).step_by(2) {
+            let feedback = PolyGF2::new(poly);
+            let x = GF2TM::<4>::with_irr(PolyGF2::new(0b10), feedback);
+            let maximal = period(Lfsr::new(feedback, 1)) == Some(15);
+            assert_eq!(maximal, x.is_primitive(), "{feedback:?}");
+        }
+    }
+
+    #[test]
+    fn test_zero_seed() {
+        let mut lfsr = Lfsr::new(PolyGF2::new(0b1011), 0);
+        assert!(lfsr.by_ref().take(10).all(|bit| bit == 0));
+        assert_eq!(lfsr.state(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_constant_feedback() {
+        Lfsr::new(PolyGF2::new(1), 1);
+    }
+}
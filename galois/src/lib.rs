@@ -1,7 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod error;
+mod field;
 mod gf2tm;
+mod lfsr;
 mod matrix;
 mod poly_gf2;
 
-pub use crate::gf2tm::GF2TM;
+pub use crate::error::CodingError;
+pub use crate::field::FiniteField;
+#[cfg(feature = "std")]
+pub use crate::gf2tm::interpolate;
+pub use crate::gf2tm::{GF2TMField, GF2TM};
+pub use crate::lfsr::Lfsr;
 pub use crate::matrix::Matrix;
-pub use crate::poly_gf2::PolyGF2;
+#[cfg(feature = "std")]
+pub use crate::poly_gf2::{format_poly, from_bytes, lift, to_bytes};
+pub use crate::poly_gf2::{hamming_distance, PolyGF2};
@@ -1,12 +1,21 @@
+#[cfg(feature = "std")]
 use crate::GF2TM;
-use num_traits::Zero;
-use polynomial::Polynomial;
-use std::fmt::Debug;
-use std::ops::{
+use core::fmt::Debug;
+use core::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
+#[cfg(feature = "std")]
+use num_traits::One;
+use num_traits::Zero;
+#[cfg(feature = "std")]
+use polynomial::Polynomial;
 
-#[derive(Eq, PartialEq, Hash, Clone, Copy, Default, PartialOrd)]
+#[derive(Eq, PartialEq, Hash, Clone, Copy, Default, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct PolyGF2 {
     pub poly: u32,
 }
@@ -22,7 +31,7 @@ impl Zero for PolyGF2 {
 }
 
 impl Debug for PolyGF2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:b}", self.poly)
     }
 }
@@ -61,18 +70,40 @@ impl Mul for PolyGF2 {
     type Output = PolyGF2;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut result = 0;
-        let mut a = self.poly;
-        let mut b = rhs.poly;
-        while b > 0 {
-            if b & 1 > 0 {
-                result ^= a;
-            }
-            a <<= 1;
-            b >>= 1;
+        #[cfg(all(feature = "clmul", target_arch = "x86_64"))]
+        if std::is_x86_feature_detected!("pclmulqdq") {
+            // SAFETY: the instruction is available on this CPU
+            return PolyGF2 {
+                poly: unsafe { mul_clmul(self.poly, rhs.poly) },
+            };
+        }
+        PolyGF2 {
+            poly: mul_scalar(self.poly, rhs.poly),
+        }
+    }
+}
+
+// Shift-and-add carryless multiplication, terms of degree 32 and above are dropped
+fn mul_scalar(mut a: u32, mut b: u32) -> u32 {
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 > 0 {
+            result ^= a;
         }
-        PolyGF2 { poly: result }
+        a <<= 1;
+        b >>= 1;
     }
+    result
+}
+
+// Same product as `mul_scalar` in a single carryless multiply instruction
+#[cfg(all(feature = "clmul", target_arch = "x86_64"))]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn mul_clmul(a: u32, b: u32) -> u32 {
+    use core::arch::x86_64::{_mm_clmulepi64_si128, _mm_cvtsi128_si64, _mm_cvtsi64_si128};
+
+    let product = _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0);
+    _mm_cvtsi128_si64(product) as u32
 }
 
 impl MulAssign for PolyGF2 {
@@ -157,6 +188,7 @@ impl From<PolyGF2> for u32 {
     }
 }
 
+#[cfg(feature = "std")]
 impl<const M: u32> From<Polynomial<GF2TM<M>>> for PolyGF2 {
     fn from(poly: Polynomial<GF2TM<M>>) -> Self {
         poly.data()
@@ -167,6 +199,86 @@ impl<const M: u32> From<Polynomial<GF2TM<M>>> for PolyGF2 {
     }
 }
 
+/// Inverse of `PolyGF2::from(Polynomial<GF2TM<M>>)`: the same polynomial with its 0/1
+/// coefficients taken in GF(2^M).
+#[cfg(feature = "std")]
+pub fn lift<const M: u32>(p: PolyGF2) -> Polynomial<GF2TM<M>> {
+    let len = u32::BITS - p.poly.leading_zeros();
+    Polynomial::new((0..len).map(|i| GF2TM::from(p.poly >> i & 1)).collect())
+}
+
+/// Packs the coefficients of `poly`, lowest degree first, into `M` bits each, least significant
+/// bit first. GF(2^8) takes one byte per symbol and GF(2^4) two symbols per byte. Symbols are
+/// packed back to back rather than into `ceil(M / 8)` whole bytes each; the two layouts only
+/// agree for M = 8.
+#[cfg(feature = "std")]
+pub fn to_bytes<const M: u32>(poly: &Polynomial<GF2TM<M>>) -> Vec<u8> {
+    let symbols = poly.data();
+    let mut bytes = vec![0u8; (symbols.len() * M as usize).div_ceil(8)];
+    for (i, symbol) in symbols.iter().enumerate() {
+        for j in 0..M as usize {
+            let bit = i * M as usize + j;
+            bytes[bit / 8] |= ((symbol.value().poly >> j & 1) as u8) << (bit % 8);
+        }
+    }
+    bytes
+}
+
+/// Inverse of `to_bytes`. Leftover bits that do not fill a symbol are ignored.
+///
+/// `Polynomial` drops zero leading coefficients, so the symbol count is not kept: trailing
+/// zero symbols of `bytes` are lost, and `to_bytes(&from_bytes(bytes))` can be shorter
+/// than `bytes`.
+#[cfg(feature = "std")]
+pub fn from_bytes<const M: u32>(bytes: &[u8]) -> Polynomial<GF2TM<M>> {
+    let irr = GF2TM::<M>::one().irr();
+    let symbols = (0..bytes.len() * 8 / M as usize)
+        .map(|i| {
+            let value = (0..M as usize)
+                .map(|j| i * M as usize + j)
+                .fold(0, |acc, bit| {
+                    acc | ((bytes[bit / 8] >> (bit % 8) & 1) as u32) << (bit - i * M as usize)
+                });
+            GF2TM::with_irr(PolyGF2::new(value), irr)
+        })
+        .collect();
+    Polynomial::new(symbols)
+}
+
+/// Writes `poly` highest degree first, as in `α^3·x^2 + α·x + 1`, with every coefficient in
+/// the `Display` form of `GF2TM`. Zero terms are left out and the zero polynomial is `0`.
+#[cfg(feature = "std")]
+pub fn format_poly<const M: u32>(poly: &Polynomial<GF2TM<M>>) -> String {
+    let terms: Vec<String> = poly
+        .data()
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, coefficient)| !coefficient.is_zero())
+        .map(|(j, coefficient)| {
+            let x = match j {
+                1 => "x".to_string(),
+                _ => format!("x^{j}"),
+            };
+            match j {
+                0 => coefficient.to_string(),
+                _ if coefficient.is_one() => x,
+                _ => format!("{coefficient}·{x}"),
+            }
+        })
+        .collect();
+    if terms.is_empty() {
+        "0".to_string()
+    } else {
+        terms.join(" + ")
+    }
+}
+
+/// Number of coefficients in which `a` and `b` differ.
+pub fn hamming_distance(a: PolyGF2, b: PolyGF2) -> u32 {
+    (a + b).weight()
+}
+
 impl PolyGF2 {
     pub fn new(poly: u32) -> Self {
         PolyGF2 { poly }
@@ -188,10 +300,25 @@ impl PolyGF2 {
         PolyGF2::default()
     }
 
+    /// Whether the polynomial has degree at least 1 and no factor of degree between 1 and
+    /// half its degree, checked by trial division.
+    pub fn is_irreducible(&self) -> bool {
+        let degree = self.degree();
+        if self.is_zero() || degree == 0 {
+            return false;
+        }
+        (2..1u32 << (degree / 2 + 1)).all(|i| !(*self % PolyGF2::new(i)).is_zero())
+    }
+
     pub fn degree(&self) -> usize {
         (self.poly.leading_zeros() ^ 31) as usize
     }
 
+    /// Hamming weight, the number of nonzero coefficients.
+    pub fn weight(&self) -> u32 {
+        self.poly.count_ones()
+    }
+
     pub fn gcd(&self, rhs: Self) -> Self {
         let mut a = *self;
         let mut b = rhs;
@@ -274,6 +401,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_poly_mul_high_degree() {
+        // Terms of degree 32 and above fall off the u32 on every path
+        assert_eq!(
+            PolyGF2::new(1 << 31) * PolyGF2::new(0b11),
+            PolyGF2::new(1 << 31)
+        );
+        let mut a = 0x9e3779b9u32;
+        for _ in 0..1000 {
+            let b = a.rotate_left(13) ^ 0x7f4a7c15;
+            assert_eq!((PolyGF2::new(a) * PolyGF2::new(b)).poly, mul_scalar(a, b));
+            a = a.wrapping_mul(0x2c1b3c6d).wrapping_add(0x297a2d39);
+        }
+    }
+
+    #[test]
+    fn test_weight_and_distance() {
+        assert_eq!(PolyGF2::new(0).weight(), 0);
+        assert_eq!(PolyGF2::new(0b1011).weight(), 3);
+        assert_eq!(PolyGF2::new(u32::MAX).weight(), 32);
+        assert_eq!(
+            hamming_distance(PolyGF2::new(0b1011), PolyGF2::new(0b1011)),
+            0
+        );
+        assert_eq!(
+            hamming_distance(PolyGF2::new(0b1011), PolyGF2::new(0b0110)),
+            3
+        );
+        // 0b1000 has degree 3 like 0b1111, yet they are three bits apart
+        assert_eq!(
+            hamming_distance(PolyGF2::new(0b1000), PolyGF2::new(0b1111)),
+            3
+        );
+        assert_eq!(hamming_distance(PolyGF2::new(0), PolyGF2::new(1 << 31)), 1);
+    }
+
     #[test]
     fn test_poly_divmod_fuzzy() {
         for a in 0..100 {
@@ -303,6 +466,23 @@ mod tests {
         assert_eq!(PolyGF2::irreducible(degree), PolyGF2::new(0b10000011));
     }
 
+    #[test]
+    fn test_is_irreducible() {
+        for degree in 1..=8 {
+            // The smallest irreducible polynomial of each degree is the first one accepted
+            let first = (1u32 << degree..1 << (degree + 1))
+                .map(PolyGF2::new)
+                .find(PolyGF2::is_irreducible);
+            assert_eq!(first, Some(PolyGF2::irreducible(degree)));
+        }
+        // x^4 + 1 = (x + 1)^4 and x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        assert!(!PolyGF2::new(0b10001).is_irreducible());
+        assert!(!PolyGF2::new(0b10101).is_irreducible());
+        assert!(PolyGF2::new(0b11111).is_irreducible());
+        assert!(!PolyGF2::new(0).is_irreducible());
+        assert!(!PolyGF2::new(1).is_irreducible());
+    }
+
     #[test]
     fn test_reduction() {
         assert_eq!(PolyGF2::new(0b110) % PolyGF2::new(0b111), PolyGF2::new(0b1),);
@@ -348,6 +528,7 @@ mod tests {
         assert_eq!(a.eval(1), 0);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_from_poly_over_gf2m() {
         let poly = Polynomial::new(vec![
@@ -358,4 +539,72 @@ mod tests {
         ]);
         assert_eq!(PolyGF2::from(poly), PolyGF2::new(0b1101));
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lift() {
+        for p in [0, 1, 0b10, 0b1101, 0b1000000000000001, u32::MAX] {
+            let p = PolyGF2::new(p);
+            assert_eq!(PolyGF2::from(lift::<4>(p)), p);
+        }
+        let lifted = lift::<3>(PolyGF2::new(0b1101));
+        assert_eq!(lifted.data(), [1u32, 0, 1, 1].map(GF2TM::<3>::from));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bytes_gf256() {
+        let symbols: Vec<_> = [0x1du32, 0x00, 0xff, 0x80].map(GF2TM::<8>::from).into();
+        let poly = Polynomial::new(symbols);
+        let bytes = to_bytes(&poly);
+        assert_eq!(bytes, vec![0x1d, 0x00, 0xff, 0x80]);
+        assert_eq!(from_bytes::<8>(&bytes), poly);
+
+        // Trailing zero symbols are the highest coefficients, which `Polynomial` trims
+        let bytes = [0x05, 0x80, 0x00, 0x00];
+        assert_eq!(from_bytes::<8>(&bytes).data().len(), 2);
+        assert_eq!(to_bytes(&from_bytes::<8>(&bytes)), vec![0x05, 0x80]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bytes_gf16() {
+        let symbols: Vec<_> = [0x3u32, 0xa, 0x0, 0xf, 0x1].map(GF2TM::<4>::from).into();
+        let poly = Polynomial::new(symbols);
+        let bytes = to_bytes(&poly);
+        assert_eq!(bytes, vec![0xa3, 0xf0, 0x01]);
+        // The padding nibble decodes as a zero leading coefficient, which is trimmed
+        assert_eq!(from_bytes::<4>(&bytes), poly);
+
+        let gf8: Polynomial<GF2TM<3>> = Polynomial::new((1..8u32).map(GF2TM::from).collect());
+        assert_eq!(to_bytes(&gf8).len(), 3);
+        assert_eq!(from_bytes::<3>(&to_bytes(&gf8)), gf8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_poly() {
+        let alpha = GF2TM::<4>::primitive_element();
+        let poly = Polynomial::new(vec![GF2TM::one(), alpha, alpha.pow(3)]);
+        assert_eq!(format_poly(&poly), "α^3·x^2 + α·x + 1");
+
+        let poly = Polynomial::new(vec![GF2TM::zero(), GF2TM::one(), GF2TM::zero(), alpha]);
+        assert_eq!(format_poly(&poly), "α·x^3 + x");
+        assert_eq!(format_poly(&Polynomial::new(vec![alpha.pow(14)])), "α^14");
+        assert_eq!(format_poly::<4>(&Polynomial::new(vec![])), "0");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = PolyGF2::new(0b1011);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "11");
+        assert_eq!(serde_json::from_str::<PolyGF2>(&json).unwrap(), a);
+
+        let block = vec![a, PolyGF2::new(0), PolyGF2::new(0x7fff)];
+        let json = serde_json::to_string(&block).unwrap();
+        assert_eq!(json, "[11,0,32767]");
+        assert_eq!(serde_json::from_str::<Vec<PolyGF2>>(&json).unwrap(), block);
+    }
 }
@@ -0,0 +1,25 @@
+//! Compile-only check that `galois` and `bch` build without `std`:
+//! `cargo build --manifest-path no_std_check/Cargo.toml`
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use bch::{CodingError, BCH};
+use galois::{Matrix, PolyGF2, GF2TM};
+
+pub fn round_trip(message: u32) -> Result<PolyGF2, CodingError> {
+    let bch = BCH::<4>::from_distance(7)?;
+    let encoded = bch.encode(PolyGF2::new(message))?;
+    bch.decode(encoded).map(|result| result.message)
+}
+
+pub fn inverses() -> Vec<GF2TM<4>> {
+    (1..16u32).map(|i| GF2TM::<4>::from(i).inv()).collect()
+}
+
+pub fn parity_check_rank() -> usize {
+    let bch = BCH::<4>::from_distance(7).unwrap();
+    let h: Matrix<GF2TM<4>> = bch.parity_check_matrix();
+    h.rank()
+}